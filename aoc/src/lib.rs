@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+pub use aoc_common::{Answer, Input, ParseError, SolveError};
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub dir: &'static str,
+    pub inputs: &'static [&'static str],
+    pub solve: fn(&str) -> Result<Answer, SolveError>,
+}
+
+macro_rules! solver {
+    ($day:expr, $part:expr, $dir:expr, [$($input:expr),+], $solve:path) => {
+        Solver {
+            day: $day,
+            part: $part,
+            dir: $dir,
+            inputs: &[$($input),+],
+            // parts that only fail on malformed inputs return a `ParseError`
+            solve: |input| $solve(input).map_err(SolveError::from),
+        }
+    };
+}
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(1, 1, "day_1.1", ["input"], day_1_1::solve_part1),
+    solver!(1, 2, "day_1.2", ["input"], day_1_2::solve_part2),
+    solver!(2, 1, "day_2.1", ["input"], day_2_1::solve_part1),
+    solver!(2, 2, "day_2.2", ["input"], day_2_2::solve_part2),
+    solver!(3, 1, "day_3.1", ["input"], day_3_1::solve_part1),
+    solver!(3, 2, "day_3.2", ["input"], day_3_2::solve_part2),
+    solver!(4, 1, "day_4.1", ["input"], day_4_1::solve_part1),
+    solver!(4, 2, "day_4.2", ["input"], day_4_2::solve_part2),
+    solver!(5, 1, "day_5.1", ["input"], day_5_1::solve_part1),
+    solver!(5, 2, "day_5.2", ["input"], day_5_2::solve_part2),
+    solver!(6, 1, "day_6.1", ["input"], day_6_1::solve_part1),
+    solver!(6, 2, "day_6.2", ["input"], day_6_2::solve_part2),
+    solver!(7, 1, "day_7.1", ["input"], day_7_1::solve_part1),
+    solver!(7, 2, "day_7.2", ["input"], day_7_2::solve_part2),
+    solver!(8, 1, "day_8.1", ["input"], day_8_1::solve_part1),
+    solver!(8, 2, "day_8.2", ["input"], day_8_2::solve_part2),
+    solver!(9, 1, "day_9.1", ["input"], day_9_1::solve_part1),
+    solver!(9, 2, "day_9.2", ["input"], day_9_2::solve_part2),
+    solver!(10, 1, "day_10.1", ["input"], day_10_1::solve_part1),
+    solver!(10, 2, "day_10.2", ["input"], day_10_2::solve_part2),
+    solver!(11, 1, "day_11.1", ["input"], day_11_1::solve_part1),
+    solver!(11, 2, "day_11.2", ["input"], day_11_2::solve_part2),
+    solver!(12, 1, "day_12.1", ["input"], day_12_1::solve_part1),
+    solver!(12, 2, "day_12.2", ["input"], day_12_2::solve_part2),
+    solver!(
+        13,
+        1,
+        "day_13.1",
+        ["input_points", "input_folds"],
+        day_13_1::solve_part1
+    ),
+    solver!(
+        13,
+        2,
+        "day_13.2",
+        ["input_points", "input_folds"],
+        day_13_2::solve_part2
+    ),
+    solver!(
+        14,
+        1,
+        "day_14.1",
+        ["input_string", "input_transform"],
+        day_14_1::solve_part1
+    ),
+    solver!(
+        14,
+        2,
+        "day_14.2",
+        ["input_string", "input_transform"],
+        day_14_2::solve_part2
+    ),
+    solver!(15, 1, "day_15.1", ["input"], day_15_1::solve_part1),
+    solver!(15, 2, "day_15.2_v2", ["input"], day_15_2_v2::solve_part2),
+    solver!(16, 1, "day_16", ["input"], day_16::solve_part1),
+    solver!(16, 2, "day_16", ["input"], day_16::solve_part2),
+    solver!(17, 1, "day_17.1", ["input"], day_17_1::solve_part1),
+    solver!(17, 2, "day_17.2", ["input"], day_17_2::solve_part2),
+    solver!(18, 1, "day_18.1", ["input"], day_18_1::solve_part1),
+    solver!(18, 2, "day_18.2", ["input"], day_18_2::solve_part2),
+    solver!(19, 1, "day_19_v2", ["input"], day_19_v2::solve_part1),
+    solver!(19, 2, "day_19_v2", ["input"], day_19_v2::solve_part2),
+    solver!(
+        20,
+        1,
+        "day_20",
+        ["input_transform_string", "input"],
+        day_20::solve_part1
+    ),
+    solver!(
+        20,
+        2,
+        "day_20",
+        ["input_transform_string", "input"],
+        day_20::solve_part2
+    ),
+    solver!(21, 1, "day_21.1", ["input"], day_21_1::solve_part1),
+    solver!(21, 2, "day_21.2", ["input"], day_21_2::solve_part2),
+    solver!(22, 1, "day_22.1", ["input"], day_22_1::solve_part1),
+    solver!(22, 2, "day_22.2", ["input"], day_22_2::solve_part2),
+    solver!(24, 1, "day_24", ["input"], day_24::solve_part1),
+    solver!(24, 2, "day_24", ["input"], day_24::solve_part2),
+    solver!(25, 1, "day_25", ["input"], day_25::solve_part1),
+];
+
+pub fn find_solver(day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn workspace_dir() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+impl Solver {
+    pub fn default_inputs(&self) -> Vec<PathBuf> {
+        self.inputs
+            .iter()
+            .map(|f| workspace_dir().join(self.dir).join(f))
+            .collect()
+    }
+}
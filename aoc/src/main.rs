@@ -0,0 +1,108 @@
+use std::{env, path::PathBuf, process, time::Instant};
+
+use aoc::{find_solver, Input, SolveError, Solver, SOLVERS};
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc run <day> <part> <input>...");
+    eprintln!("       aoc run --all");
+    process::exit(2);
+}
+
+fn print_header() {
+    println!("{:>3} | {:>4} | {:>12} | Answer", "Day", "Part", "Time");
+    println!("{:-<4}+{:-<6}+{:-<14}+{:-<21}", "", "", "", "");
+}
+
+// Multi-line answers (e.g. day 13) are continued on the next rows of the table
+fn print_row(solver: &Solver, answer: &str, elapsed: Option<f64>) {
+    let time = elapsed.map_or(String::from("-"), |t| format!("{:.3} ms", t));
+    let mut lines = answer.lines();
+
+    println!(
+        "{:>3} | {:>4} | {:>12} | {}",
+        solver.day,
+        solver.part,
+        time,
+        lines.next().unwrap_or("")
+    );
+    for l in lines {
+        println!("{:>3} | {:>4} | {:>12} | {}", "", "", "", l);
+    }
+}
+
+// Why a solver gave no answer
+enum Failure {
+    Unreadable,
+    Unsolved,
+}
+
+fn run(solver: &Solver, paths: &[PathBuf]) -> Result<(), Failure> {
+    match Input::read(paths) {
+        Ok(input) => {
+            let start = Instant::now();
+            let result = (solver.solve)(&input.text);
+            let elapsed = start.elapsed().as_secs_f64() * 1000.0;
+
+            match result {
+                Ok(answer) => {
+                    print_row(solver, &answer.to_string(), Some(elapsed));
+                    Ok(())
+                }
+                Err(SolveError::Parse(e)) => {
+                    print_row(solver, &format!("invalid input: {}", input.locate(e)), None);
+                    Err(Failure::Unsolved)
+                }
+                Err(e) => {
+                    print_row(solver, &format!("no answer: {}", e), None);
+                    Err(Failure::Unsolved)
+                }
+            }
+        }
+        Err(e) => {
+            print_row(solver, &format!("could not read input: {}", e), None);
+            Err(Failure::Unreadable)
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let ok = match args.iter().map(|s| s.as_str()).collect::<Vec<_>>()[..] {
+        ["run", "--all"] => {
+            print_header();
+            // Days without an input checked in are reported but do not fail the run
+            let failures = SOLVERS
+                .iter()
+                .filter(|s| matches!(run(s, &s.default_inputs()), Err(Failure::Unsolved)))
+                .count();
+            if failures > 0 {
+                eprintln!("{} of {} solvers failed", failures, SOLVERS.len());
+            }
+            failures == 0
+        }
+        ["run", day, part, ref inputs @ ..] => {
+            let (day, part) = match (day.parse(), part.parse()) {
+                (Ok(day), Ok(part)) => (day, part),
+                _ => usage(),
+            };
+            let solver = find_solver(day, part).unwrap_or_else(|| {
+                eprintln!("No solver for day {} part {}", day, part);
+                process::exit(2);
+            });
+            let paths = if inputs.is_empty() {
+                solver.default_inputs()
+            } else {
+                inputs.iter().map(PathBuf::from).collect()
+            };
+
+            print_header();
+            run(solver, &paths).is_ok()
+        }
+        _ => usage(),
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,164 @@
+use aoc::{workspace_dir, Input};
+use day_24::{
+    is_valid_model_number, model_digits, parse_program, Alu, AluError, CompiledProgram,
+    Instruction, Operand, Register,
+};
+
+fn monad_program() -> Vec<Instruction> {
+    let input = Input::read(&[workspace_dir().join("day_24").join("input")]).unwrap();
+    parse_program(&input.text).unwrap()
+}
+
+fn run(program: &str, input: &[i64]) -> Result<Alu, AluError> {
+    let mut alu = Alu::default();
+    alu.run(&parse_program(program).unwrap(), input.iter().copied())?;
+    Ok(alu)
+}
+
+#[test]
+fn programs_round_trip_through_display() {
+    let input = Input::read(&[workspace_dir().join("day_24").join("input")]).unwrap();
+    let program = parse_program(&input.text).unwrap();
+
+    assert_eq!(program.len(), 252);
+    assert_eq!(
+        program[5],
+        Instruction::Add(Register::X, Operand::Value(14))
+    );
+    let printed: Vec<String> = program.iter().map(|i| i.to_string()).collect();
+    assert_eq!(printed.join("\n") + "\n", input.text);
+}
+
+#[test]
+fn example_programs() {
+    let binary = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2\n";
+    let alu = run(binary, &[13]).unwrap();
+    assert_eq!(
+        Register::ALL.map(|r| alu.get(r)),
+        [1, 1, 0, 1],
+        "13 is 0b1101"
+    );
+
+    let three_times = "inp z\ninp x\nmul z 3\neql z x\n";
+    assert_eq!(run(three_times, &[2, 6]).unwrap().get(Register::Z), 1);
+    assert_eq!(run(three_times, &[2, 7]).unwrap().get(Register::Z), 0);
+
+    assert_eq!(run("inp x\nmul x -1\n", &[5]).unwrap().get(Register::X), -5);
+    assert_eq!(run("inp x\ndiv x 2\n", &[-7]).unwrap().get(Register::X), -3);
+}
+
+#[test]
+fn crashes_are_reported() {
+    assert_eq!(
+        run("inp x\ndiv x y\n", &[1]),
+        Err(AluError::DivisionByZero { at: 1 })
+    );
+    assert_eq!(
+        run("inp x\nmod x 3\n", &[-1]),
+        Err(AluError::NegativeModulo { at: 1 })
+    );
+    assert_eq!(
+        run("inp x\nmod x 0\n", &[1]),
+        Err(AluError::NegativeModulo { at: 1 })
+    );
+    assert_eq!(
+        run("inp x\ninp y\n", &[1]),
+        Err(AluError::MissingInput { at: 1 })
+    );
+    assert_eq!(
+        run("inp w\nmul w 999999999999\nmul w w\n", &[1]),
+        Err(AluError::Overflow { at: 2 })
+    );
+    assert_eq!(
+        run("inp w\nadd w 9223372036854775807\n", &[1]),
+        Err(AluError::Overflow { at: 1 })
+    );
+    assert_eq!(
+        run("inp w\nadd w -9223372036854775807\ndiv w -1\n", &[-1]),
+        Err(AluError::Overflow { at: 2 })
+    );
+}
+
+#[test]
+fn malformed_instructions_are_located() {
+    let e = parse_program("inp w\nadd x 1\nmul y\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "3:6: expected a register or a number, found nothing"
+    );
+
+    let e = parse_program("sub x 1\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        r#"1:1: expected one of inp add mul div mod eql, found "sub""#
+    );
+}
+
+#[test]
+fn interpreter_agrees_with_the_hand_written_monad() {
+    let program = monad_program();
+
+    for model_number in [99799212949967, 34198111816311, 13579246899999] {
+        let digits: Vec<u32> = model_digits(model_number)
+            .iter()
+            .map(|&d| d as u32)
+            .collect();
+        let expected = day_24::monad(&digits.try_into().unwrap());
+
+        let mut alu = Alu::default();
+        alu.run(&program, model_digits(model_number)).unwrap();
+        assert_eq!(alu.get(Register::Z), expected as i64);
+    }
+
+    assert!(is_valid_model_number(&program, 99799212949967).unwrap());
+    assert!(!is_valid_model_number(&program, 13579246899999).unwrap());
+}
+
+#[test]
+fn compiled_programs_behave_like_the_interpreter() {
+    let program = monad_program();
+    let compiled = CompiledProgram::new(&program);
+    assert!(compiled.len() < program.len());
+
+    for model_number in [99799212949967, 34198111816311, 13579246899999] {
+        let mut interpreted = Alu::default();
+        interpreted
+            .run(&program, model_digits(model_number))
+            .unwrap();
+        let mut alu = Alu::default();
+        compiled.run(&mut alu, model_digits(model_number)).unwrap();
+        assert_eq!(alu, interpreted);
+    }
+
+    let compile_and_run = |program: &str, input: &[i64]| {
+        let mut alu = Alu::default();
+        CompiledProgram::new(&parse_program(program).unwrap())
+            .run(&mut alu, input.iter().copied())
+            .map(|_| alu)
+    };
+    for (program, input) in [
+        ("inp x\ndiv x y\n", &[1][..]),
+        ("inp x\nmod x 3\n", &[-1]),
+        ("inp x\nmod x 0\n", &[1]),
+        ("inp x\ninp y\n", &[1]),
+        ("inp w\nmul w 999999999999\nmul w w\n", &[1]),
+        ("inp w\nadd w 9223372036854775807\n", &[1]),
+        (
+            "inp w\nmul x 0\nadd x w\nadd x 9223372036854775807\nadd w x\n",
+            &[1],
+        ),
+        ("inp w\nadd w -9223372036854775807\ndiv w -1\n", &[-1]),
+        ("inp z\ninp x\nmul z 3\neql z x\n", &[2, 6]),
+        (
+            "inp w\nmul x 0\nadd x w\nmul y 0\nadd y 7\ndiv y 1\neql y 7\n",
+            &[3],
+        ),
+    ] {
+        assert_eq!(
+            compile_and_run(program, input),
+            run(program, input),
+            "{}",
+            program
+        );
+    }
+}
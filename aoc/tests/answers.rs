@@ -0,0 +1,164 @@
+use std::thread;
+
+use aoc::{workspace_dir, Answer, Input, SolveError};
+
+// Every line of answers.txt is `<crate dir> <part> <input file>[,<input file>...] <answer>`.
+// Rendered answers have their rows separated by `\n`.
+const MANIFEST: &str = include_str!("answers.txt");
+
+struct Case<'a> {
+    dir: &'a str,
+    part: u8,
+    inputs: Vec<&'a str>,
+    expected: String,
+}
+
+fn parse_manifest(manifest: &str) -> Vec<Case<'_>> {
+    manifest
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            let fields: Vec<&str> = l.split_whitespace().collect();
+            assert_eq!(fields.len(), 4, "malformed manifest line: {}", l);
+
+            Case {
+                dir: fields[0],
+                part: fields[1].parse().unwrap(),
+                inputs: fields[2].split(',').collect(),
+                expected: fields[3].replace("\\n", "\n"),
+            }
+        })
+        .collect()
+}
+
+// Unlike aoc::SOLVERS, this covers every crate, including the alternative versions
+type Solve = fn(&str) -> Result<Answer, SolveError>;
+
+macro_rules! solve {
+    ($solve:path) => {
+        |input| $solve(input).map_err(SolveError::from)
+    };
+}
+
+fn solver(dir: &str, part: u8) -> Option<Solve> {
+    let solve: Solve = match (dir, part) {
+        ("day_1.1", 1) => solve!(day_1_1::solve_part1),
+        ("day_1.2", 2) => solve!(day_1_2::solve_part2),
+        ("day_2.1", 1) => solve!(day_2_1::solve_part1),
+        ("day_2.2", 2) => solve!(day_2_2::solve_part2),
+        ("day_3.1", 1) => solve!(day_3_1::solve_part1),
+        ("day_3.2", 2) => solve!(day_3_2::solve_part2),
+        ("day_4.1", 1) => solve!(day_4_1::solve_part1),
+        ("day_4.2", 2) => solve!(day_4_2::solve_part2),
+        ("day_5.1", 1) => solve!(day_5_1::solve_part1),
+        ("day_5.2", 2) => solve!(day_5_2::solve_part2),
+        ("day_6.1", 1) => solve!(day_6_1::solve_part1),
+        ("day_6.2", 2) => solve!(day_6_2::solve_part2),
+        ("day_7.1", 1) => solve!(day_7_1::solve_part1),
+        ("day_7.2", 2) => solve!(day_7_2::solve_part2),
+        ("day_8.1", 1) => solve!(day_8_1::solve_part1),
+        ("day_8.2", 2) => solve!(day_8_2::solve_part2),
+        ("day_9.1", 1) => solve!(day_9_1::solve_part1),
+        ("day_9.2", 2) => solve!(day_9_2::solve_part2),
+        ("day_10.1", 1) => solve!(day_10_1::solve_part1),
+        ("day_10.2", 2) => solve!(day_10_2::solve_part2),
+        ("day_11.1", 1) => solve!(day_11_1::solve_part1),
+        ("day_11.2", 2) => solve!(day_11_2::solve_part2),
+        ("day_12.1", 1) => solve!(day_12_1::solve_part1),
+        ("day_12.2", 2) => solve!(day_12_2::solve_part2),
+        ("day_13.1", 1) => solve!(day_13_1::solve_part1),
+        ("day_13.2", 2) => solve!(day_13_2::solve_part2),
+        ("day_14.1", 1) => solve!(day_14_1::solve_part1),
+        ("day_14.2", 2) => solve!(day_14_2::solve_part2),
+        ("day_15.1", 1) => solve!(day_15_1::solve_part1),
+        ("day_15.2_v1", 2) => solve!(day_15_2_v1::solve_part2),
+        ("day_15.2_v2", 2) => solve!(day_15_2_v2::solve_part2),
+        ("day_16", 1) => solve!(day_16::solve_part1),
+        ("day_16", 2) => solve!(day_16::solve_part2),
+        ("day_17.1", 1) => solve!(day_17_1::solve_part1),
+        ("day_17.2", 2) => solve!(day_17_2::solve_part2),
+        ("day_18.1", 1) => solve!(day_18_1::solve_part1),
+        ("day_18.2", 2) => solve!(day_18_2::solve_part2),
+        ("day_19_v1", 1) => solve!(day_19_v1::solve_part1),
+        ("day_19_v1", 2) => solve!(day_19_v1::solve_part2),
+        ("day_19_v2", 1) => solve!(day_19_v2::solve_part1),
+        ("day_19_v2", 2) => solve!(day_19_v2::solve_part2),
+        ("day_20", 1) => solve!(day_20::solve_part1),
+        ("day_20", 2) => solve!(day_20::solve_part2),
+        ("day_21.1", 1) => solve!(day_21_1::solve_part1),
+        ("day_21.2", 2) => solve!(day_21_2::solve_part2),
+        ("day_22.1", 1) => solve!(day_22_1::solve_part1),
+        ("day_22.2", 2) => solve!(day_22_2::solve_part2),
+        ("day_24", 1) => solve!(day_24::solve_part1),
+        ("day_24", 2) => solve!(day_24::solve_part2),
+        ("day_25", 1) => solve!(day_25::solve_part1),
+        _ => return None,
+    };
+
+    Some(solve)
+}
+
+fn name(case: &Case) -> String {
+    format!(
+        "{} part {} on {}",
+        case.dir,
+        case.part,
+        case.inputs.join(",")
+    )
+}
+
+fn check(case: &Case) -> Result<(), String> {
+    let name = name(case);
+
+    let solve = solver(case.dir, case.part).ok_or(format!("{}: unknown solver", name))?;
+    let paths: Vec<_> = case
+        .inputs
+        .iter()
+        .map(|f| workspace_dir().join(case.dir).join(f))
+        .collect();
+    let input = Input::read(&paths).map_err(|e| format!("{}: {}", name, e))?;
+
+    let answer = solve(&input.text)
+        .map_err(|e| match e {
+            SolveError::Parse(e) => format!("{}: {}", name, input.locate(e)),
+            e => format!("{}: {}", name, e),
+        })?
+        .to_string();
+    if answer == case.expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: expected {:?}, got {:?}",
+            name, case.expected, answer
+        ))
+    }
+}
+
+#[test]
+fn all_answers() {
+    let cases = parse_manifest(MANIFEST);
+
+    let failures: Vec<String> = thread::scope(|s| {
+        let handles: Vec<_> = cases
+            .iter()
+            .map(|case| s.spawn(move || check(case)))
+            .collect();
+
+        handles
+            .into_iter()
+            .zip(cases.iter())
+            .filter_map(|(h, case)| match h.join() {
+                Ok(result) => result.err(),
+                Err(_) => Some(format!("{}: panicked", name(case))),
+            })
+            .collect()
+    });
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} answers are wrong:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,405 @@
+use aoc::{workspace_dir, Input, SolveError};
+use day_16::{
+    parse_transmission, BitReader, DecodeError, EncodeError, EvalError, LengthType, Operator,
+    Packet, PacketContent, MAX_DEPTH,
+};
+
+const TRANSMISSIONS: [&str; 8] = [
+    "input",
+    "test_input_0",
+    "test_input_1",
+    "test_input_2",
+    "test_input_3",
+    "test_input_4",
+    "test_compute_2",
+    "test_compute_5",
+];
+
+fn transmission(name: &str) -> String {
+    Input::read(&[workspace_dir().join("day_16").join(name)])
+        .unwrap()
+        .text
+}
+
+#[test]
+fn transmissions_round_trip_through_the_encoder() {
+    for name in TRANSMISSIONS {
+        let hex = transmission(name);
+        let packet = parse_transmission(&hex).unwrap();
+        let encoded = packet.encode().unwrap();
+
+        // operators keep their length type, so only the padding can differ
+        assert_eq!(parse_transmission(&encoded).unwrap(), packet, "{}", name);
+        let padding = hex.trim().strip_prefix(encoded.as_str()).unwrap();
+        assert!(padding.chars().all(|c| c == '0'), "{}", name);
+        assert!(packet.bit_length() <= 4 * encoded.len(), "{}", name);
+    }
+}
+
+#[test]
+fn encoded_packets_match_the_puzzle_examples() {
+    assert_eq!(Packet::literal(6, 2021).encode().unwrap(), "D2FE28");
+    assert_eq!(Packet::literal(0, 0).encode().unwrap(), "1000");
+
+    // an operator given with length type 1
+    let maximum = Packet::operator(
+        7,
+        Operator::Maximum,
+        vec![
+            Packet::literal(2, 1),
+            Packet::literal(4, 2),
+            Packet::literal(1, 3),
+        ],
+    );
+    assert_eq!(maximum.encode().unwrap(), "EE00D40C823060");
+}
+
+#[test]
+fn long_operators_use_the_length_of_their_subpackets() {
+    let sum = Packet::operator(0, Operator::Sum, vec![Packet::literal(0, 1); 2048]);
+    let encoded = sum.encode().unwrap();
+
+    assert!(encoded.starts_with('0'), "length type 0");
+    assert_eq!(parse_transmission(&encoded).unwrap(), sum);
+    assert_eq!(day_16::solve_part2(&encoded).unwrap().to_string(), "2048");
+}
+
+#[test]
+fn invalid_packets_are_not_encoded() {
+    assert_eq!(
+        Packet::literal(8, 1).encode(),
+        Err(EncodeError::Overflow {
+            field: "version",
+            value: 8
+        })
+    );
+    assert_eq!(
+        Packet::operator(0, Operator::Sum, vec![Packet::literal(0, 1); 3000]).encode(),
+        Err(EncodeError::Overflow {
+            field: "length of subpackets",
+            value: 33000
+        })
+    );
+    let value = |v| Packet::literal(0, v);
+    let three_way = Packet::operator(0, Operator::LessThan, vec![value(1), value(2), value(3)]);
+    assert_eq!(
+        three_way.encode(),
+        Err(EncodeError::BadArity {
+            operator: Operator::LessThan,
+            subpackets: 3
+        })
+    );
+    assert_eq!(
+        Packet::operator(0, Operator::Maximum, vec![])
+            .encode()
+            .unwrap_err()
+            .to_string(),
+        "the maximum needs at least 1 subpacket, not 0"
+    );
+}
+
+#[test]
+fn bits_are_read_across_digits() {
+    let mut reader = BitReader::new("D2FE28").unwrap();
+    assert_eq!(reader.read(3, "version"), Ok(6));
+    assert_eq!(reader.read(3, "type ID"), Ok(4));
+    assert_eq!(reader.read(15, "groups"), Ok(0b101111111000101));
+    assert_eq!(reader.position(), 21);
+    assert_eq!(reader.remaining(), 3);
+    assert_eq!(
+        reader.read(4, "padding"),
+        Err(DecodeError::Truncated {
+            at: 21,
+            field: "padding"
+        })
+    );
+
+    let mut reader = BitReader::new("FEDCBA9876543210F").unwrap();
+    assert_eq!(reader.read(2, "start"), Ok(3));
+    assert_eq!(
+        reader.read(64, "value"),
+        Ok((0xFEDCBA9876543210F_u128 >> 2) as u64)
+    );
+    assert_eq!(BitReader::new("12G4").unwrap_err(), 2);
+}
+
+#[test]
+fn malformed_transmissions_are_reported() {
+    let error = |hex: &str| parse_transmission(hex).unwrap_err().to_string();
+
+    assert_eq!(
+        error("D2FE"),
+        "1:5: expected the literal value starting at bit 16, found nothing"
+    );
+    assert_eq!(
+        error("EE00D40C8230"),
+        "1:13: expected the literal value starting at bit 46, found nothing"
+    );
+    assert_eq!(
+        error("3800FF45291200"),
+        r#"1:2: expected subpackets filling the 63 bits given at bit 7, found "8""#
+    );
+    assert_eq!(
+        error("38006B45291200"),
+        r#"1:2: expected subpackets filling the 26 bits given at bit 7, found "8""#
+    );
+    assert_eq!(
+        error("D2FE28xy"),
+        r#"1:7: expected a hexadecimal digit, found "x""#
+    );
+
+    let large = Packet::literal(0, u64::MAX).encode().unwrap();
+    assert_eq!(parse_transmission(&large), Ok(Packet::literal(0, u64::MAX)));
+    // 17 groups of 4 bits
+    let bits = format!("000100{}00001000", "11111".repeat(16));
+    let too_large: String = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|d| {
+            format!(
+                "{:X}",
+                u8::from_str_radix(std::str::from_utf8(d).unwrap(), 2).unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        error(&too_large),
+        r#"1:2: expected a literal value of at most 64 bits at bit 6, found "3""#
+    );
+}
+
+#[test]
+fn large_transmissions_are_decoded() {
+    let depth = 200;
+    let mut packet = Packet::literal(1, 1);
+    for _ in 0..depth {
+        packet = Packet::operator(
+            1,
+            Operator::Sum,
+            vec![packet.clone(), Packet::literal(1, 0)],
+        );
+    }
+    let hex = packet.encode().unwrap();
+
+    assert_eq!(parse_transmission(&hex), Ok(packet));
+    assert_eq!(
+        day_16::solve_part1(&hex).unwrap().to_string(),
+        (2 * depth + 1).to_string()
+    );
+}
+
+// Sums of a single subpacket nested `depth` deep around a literal
+fn nested_sums(depth: usize) -> Packet {
+    let mut packet = Packet::literal(1, 1);
+    for _ in 0..depth {
+        packet = Packet::operator(1, Operator::Sum, vec![packet]);
+    }
+    packet
+}
+
+#[test]
+fn deeply_nested_transmissions_are_rejected() {
+    let packet = nested_sums(MAX_DEPTH);
+    let hex = packet.encode().unwrap();
+    assert_eq!(parse_transmission(&hex), Ok(packet));
+    assert_eq!(day_16::solve_part2(&hex).unwrap().to_string(), "1");
+
+    // each sum takes 18 bits
+    let hex = nested_sums(MAX_DEPTH + 1).encode().unwrap();
+    let at = 18 * MAX_DEPTH;
+    assert_eq!(
+        parse_transmission(&hex).unwrap_err().to_string(),
+        format!(
+            "1:{}: expected operators nested at most {} deep at bit {}, found \"{}\"",
+            at / 4 + 1,
+            MAX_DEPTH,
+            at,
+            &hex[at / 4..at / 4 + 1]
+        )
+    );
+
+    // about 900 KB, nested 200 000 deep: the digits are built without packets, as their
+    // recursive drop would overflow the stack
+    let sums = "001000100000000001".repeat(200_000) + "00110000001" + "00000";
+    let hex: String = sums
+        .as_bytes()
+        .chunks(4)
+        .map(|d| format!("{:X}", d.iter().fold(0, |acc, &b| 2 * acc + (b - b'0'))))
+        .collect();
+    let error = day_16::solve_part1(&hex).unwrap_err().to_string();
+    assert!(
+        error.contains("expected operators nested at most"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn packets_are_shown_as_trees() {
+    let packet = parse_transmission(&transmission("test_input_4")).unwrap();
+    assert!(matches!(
+        packet.content,
+        PacketContent::Operator {
+            length_type: LengthType::TotalLength,
+            ..
+        }
+    ));
+
+    assert_eq!(
+        packet.tree().to_string(),
+        "sum (v5, length type 0: 91 bits)
+└── sum (v1, length type 1: 1 subpacket)
+    └── sum (v3, length type 1: 5 subpackets)
+        ├── literal 6 (v7)
+        ├── literal 6 (v6)
+        ├── literal 12 (v5)
+        ├── literal 15 (v2)
+        └── literal 15 (v2)
+"
+    );
+
+    let packet = parse_transmission("9C0141080250320F1802104A08").unwrap();
+    assert_eq!(
+        packet.tree().to_string(),
+        "equal to (v4, length type 0: 80 bits)
+├── sum (v2, length type 1: 2 subpackets)
+│   ├── literal 1 (v2)
+│   └── literal 3 (v4)
+└── product (v6, length type 1: 2 subpackets)
+    ├── literal 2 (v0)
+    └── literal 2 (v2)
+"
+    );
+}
+
+#[test]
+fn packets_are_shown_as_expressions() {
+    let expression = |hex: &str| parse_transmission(hex).unwrap().expression().to_string();
+
+    assert_eq!(expression("9C0141080250320F1802104A08"), "1 + 3 == 2 * 2");
+    assert_eq!(expression("880086C3E88112"), "min(7, 8, 9)");
+    assert_eq!(expression("D8005AC2A8F0"), "5 < 15");
+
+    let value = |v| Packet::literal(0, v);
+    let formula = Packet::operator(
+        0,
+        Operator::Maximum,
+        vec![
+            value(3),
+            Packet::operator(
+                0,
+                Operator::Product,
+                vec![
+                    Packet::operator(0, Operator::Sum, vec![value(1), value(2)]),
+                    value(7),
+                ],
+            ),
+        ],
+    );
+    assert_eq!(formula.expression().to_string(), "max(3, (1 + 2) * 7)");
+
+    let nested = Packet::operator(
+        0,
+        Operator::GreaterThan,
+        vec![
+            Packet::operator(0, Operator::LessThan, vec![value(1), value(2)]),
+            Packet::operator(0, Operator::Sum, vec![value(4)]),
+        ],
+    );
+    assert_eq!(nested.expression().to_string(), "(1 < 2) > 4");
+}
+
+#[test]
+fn operators_are_checked_when_decoding() {
+    let packet = parse_transmission("9C0141080250320F1802104A08").unwrap();
+    assert_eq!(packet.type_id(), 7);
+    assert!(matches!(
+        packet.content,
+        PacketContent::Operator {
+            operator: Operator::EqualTo,
+            ..
+        }
+    ));
+
+    // the encoder refuses these packets: 1 < 2 < 3
+    assert_eq!(
+        parse_transmission("1A00C408821060")
+            .unwrap_err()
+            .to_string(),
+        r#"1:1: expected exactly 2 subpackets for the less than at bit 0, not 3, found "1""#
+    );
+
+    // 1 + max()
+    assert_eq!(
+        parse_transmission("020084087000").unwrap_err().to_string(),
+        r#"1:8: expected at least 1 subpacket for the maximum at bit 29, not 0, found "8""#
+    );
+}
+
+#[test]
+fn overflows_are_located() {
+    let value = |v| Packet::literal(0, v);
+    let big = Packet::operator(0, Operator::Product, vec![value(1 << 40), value(1 << 30)]);
+    let packet = Packet::operator(
+        0,
+        Operator::Maximum,
+        vec![
+            value(3),
+            Packet::operator(0, Operator::Sum, vec![value(1), big.clone()]),
+        ],
+    );
+
+    assert_eq!(
+        packet.evaluate(),
+        Err(EvalError::Overflow {
+            path: vec![1, 1],
+            operator: Operator::Product
+        })
+    );
+    assert_eq!(
+        packet.evaluate().unwrap_err().to_string(),
+        "the product of subpacket 1.1 does not fit in 64 bits"
+    );
+    assert_eq!(packet.bit_offset(&[1, 1]), 18 + 11 + 18 + 11);
+    // the transmission itself is well-formed
+    assert_eq!(
+        day_16::solve_part2(&packet.encode().unwrap()),
+        Err(SolveError::Evaluation(
+            "the product of subpacket 1.1 does not fit in 64 bits, at bit 58".into()
+        ))
+    );
+
+    let sum = Packet::operator(0, Operator::Sum, vec![value(u64::MAX), value(1)]);
+    assert_eq!(
+        sum.evaluate().unwrap_err().to_string(),
+        "the sum of the outermost packet does not fit in 64 bits"
+    );
+    assert_eq!(big.bit_offset(&[1]), 18 + value(1 << 40).bit_length());
+}
+
+#[test]
+fn operators_are_checked_when_evaluating() {
+    let value = |v| Packet::literal(0, v);
+    let empty = Packet::operator(0, Operator::Minimum, vec![]);
+    assert_eq!(
+        empty.evaluate(),
+        Err(EvalError::BadArity {
+            path: vec![],
+            operator: Operator::Minimum,
+            subpackets: 0
+        })
+    );
+
+    let packet = Packet::operator(
+        0,
+        Operator::Sum,
+        vec![
+            value(1),
+            Packet::operator(0, Operator::EqualTo, vec![value(1)]),
+        ],
+    );
+    assert_eq!(
+        packet.evaluate().unwrap_err().to_string(),
+        "the equal to of subpacket 1 needs exactly 2 subpackets, not 1"
+    );
+}
@@ -0,0 +1,161 @@
+use day_21_2::{
+    compute_outcomes, compute_outcomes_simple, GameError, Rules, ScoreState, MAX_TARGET,
+};
+
+fn start(p1: u16, p2: u16) -> ScoreState {
+    ScoreState {
+        positions: [p1 - 1, p2 - 1],
+        scores: [0, 0],
+        player: 0,
+    }
+}
+
+#[test]
+fn roll_distributions_are_derived_from_the_die() {
+    assert_eq!(
+        Rules::default().roll_distribution().unwrap(),
+        [0, 0, 0, 1, 3, 6, 7, 6, 3, 1]
+    );
+
+    let rules = |die_faces, rolls| Rules {
+        die_faces,
+        rolls,
+        ..Rules::default()
+    };
+    assert_eq!(
+        rules(4, 2).roll_distribution().unwrap(),
+        [0, 0, 1, 2, 3, 4, 3, 2, 1]
+    );
+    assert_eq!(
+        rules(6, 1).roll_distribution().unwrap(),
+        [0, 1, 1, 1, 1, 1, 1]
+    );
+    assert_eq!(rules(1, 5).roll_distribution().unwrap(), [0, 0, 0, 0, 0, 1]);
+    assert_eq!(
+        rules(2, 10)
+            .roll_distribution()
+            .unwrap()
+            .iter()
+            .sum::<u64>(),
+        1024
+    );
+}
+
+#[test]
+fn the_puzzle_game_is_the_default() {
+    let rules = Rules::default();
+
+    assert_eq!(
+        compute_outcomes(&rules, start(4, 8)).unwrap(),
+        [444356092776315, 341960390180808]
+    );
+    assert_eq!(
+        compute_outcomes_simple(&rules, start(4, 8)).unwrap(),
+        [444356092776315, 341960390180808]
+    );
+}
+
+#[test]
+fn variants_are_played_without_code_changes() {
+    let variant = Rules {
+        board_size: 10,
+        target: 30,
+        die_faces: 4,
+        rolls: 2,
+    };
+    let outcomes = compute_outcomes(&variant, start(4, 8)).unwrap();
+    assert_eq!(outcomes, [58558720892996745, 47601545430547156]);
+    assert_eq!(
+        compute_outcomes_simple(&variant, start(4, 8)).unwrap(),
+        outcomes
+    );
+
+    let small = Rules {
+        board_size: 7,
+        target: 15,
+        die_faces: 2,
+        rolls: 1,
+    };
+    for (p1, p2) in [(1, 1), (3, 7), (7, 2)] {
+        assert_eq!(
+            compute_outcomes(&small, start(p1, p2)).unwrap(),
+            compute_outcomes_simple(&small, start(p1, p2)).unwrap()
+        );
+    }
+
+    // the first player wins in every universe of the first turn
+    let quick = Rules {
+        target: 1,
+        ..Rules::default()
+    };
+    assert_eq!(compute_outcomes(&quick, start(4, 8)).unwrap(), [27, 0]);
+
+    // a single universe: the first player moves from 4 to 5 and wins
+    let deterministic = Rules {
+        board_size: 10,
+        target: 5,
+        die_faces: 1,
+        rolls: 1,
+    };
+    assert_eq!(
+        compute_outcomes(&deterministic, start(4, 8)).unwrap(),
+        [1, 0]
+    );
+}
+
+#[test]
+fn games_that_cannot_be_counted_are_errors() {
+    let rules = |board_size, target, die_faces, rolls| Rules {
+        board_size,
+        target,
+        die_faces,
+        rolls,
+    };
+
+    // the number of universes grows past 64 bits
+    for variant in [rules(10, 21, 6, 3), rules(10, 40, 3, 3)] {
+        assert_eq!(
+            compute_outcomes(&variant, start(4, 8)),
+            Err(GameError::Overflow)
+        );
+        assert_eq!(
+            compute_outcomes_simple(&variant, start(4, 8)),
+            Err(GameError::Overflow)
+        );
+    }
+    assert_eq!(
+        rules(10, 21, 100, 20).roll_distribution(),
+        Err(GameError::Overflow)
+    );
+    assert_eq!(
+        compute_outcomes(&rules(10, 21, 100, 20), start(4, 8))
+            .unwrap_err()
+            .to_string(),
+        "the number of universes does not fit in 64 bits"
+    );
+
+    assert_eq!(
+        compute_outcomes(&rules(10, u16::MAX, 3, 3), start(4, 8)),
+        Err(GameError::TargetTooLarge { target: u16::MAX })
+    );
+    assert_eq!(
+        compute_outcomes(&rules(0, 21, 3, 3), start(1, 1)),
+        Err(GameError::Empty)
+    );
+    assert_eq!(
+        compute_outcomes(&rules(5, 21, 3, 3), start(4, 8)),
+        Err(GameError::InvalidState)
+    );
+
+    // a single universe, as deep as the target allows
+    assert_eq!(
+        compute_outcomes(&rules(1, MAX_TARGET, 1, 1), start(1, 1)),
+        Ok([1, 0])
+    );
+    // the first player wraps around to space 1 from the last one of a 16-bit board, and
+    // scores slower than the second one
+    assert_eq!(
+        compute_outcomes(&rules(u16::MAX, MAX_TARGET, 1, 1), start(u16::MAX, 1)),
+        Ok([0, 1])
+    );
+}
@@ -0,0 +1,133 @@
+use aoc::{workspace_dir, Input};
+use day_24::{
+    analyse, is_valid_model_number, parse_program, Constraint, DigitSearch, TooManyDigits,
+};
+
+fn monad_input() -> String {
+    Input::read(&[workspace_dir().join("day_24").join("input")])
+        .unwrap()
+        .text
+}
+
+#[test]
+fn constraints_pair_push_and_pop_blocks() {
+    let monad = analyse(&monad_input()).unwrap();
+
+    assert_eq!(monad.digits, 14);
+    assert_eq!(monad.constraints.len(), 7);
+    assert_eq!(
+        monad.constraints[0],
+        Constraint {
+            pushed: 4,
+            popped: 5,
+            offset: -7
+        }
+    );
+    assert_eq!(monad.constraints[0].to_string(), "d5 = d4 - 7");
+    assert_eq!(monad.constraints[3].to_string(), "d10 = d9 + 5");
+}
+
+#[test]
+fn extremal_model_numbers_are_accepted_by_the_program() {
+    let input = monad_input();
+    let program = parse_program(&input).unwrap();
+    let monad = analyse(&input).unwrap();
+
+    assert!(is_valid_model_number(&program, monad.largest()).unwrap());
+    assert!(is_valid_model_number(&program, monad.smallest()).unwrap());
+    assert!(!is_valid_model_number(&program, monad.largest() + 1).unwrap());
+    assert!(!is_valid_model_number(&program, monad.smallest() - 1).unwrap());
+}
+
+#[test]
+fn programs_not_shaped_like_monad_are_rejected() {
+    let input = monad_input();
+
+    let changed = input.replacen("div z 26", "div z 2", 1);
+    assert_eq!(
+        analyse(&changed).unwrap_err().to_string(),
+        r#"95:1: expected `div z 1` or `div z 26`, found "div z 2""#
+    );
+
+    let truncated: String = input
+        .lines()
+        .take(30)
+        .map(|l| l.to_string() + "\n")
+        .collect();
+    assert_eq!(
+        analyse(&truncated).unwrap_err().to_string(),
+        "31:1: expected 6 more instructions, found nothing"
+    );
+
+    // the first digit pushed would not fit in a base 26 digit
+    let changed = input.replacen("add y 12\n", "add y 17\n", 1);
+    assert_eq!(
+        analyse(&changed).unwrap_err().to_string(),
+        r#"16:1: expected `add y <n>` with -1 <= n <= 16, found "add y 17""#
+    );
+
+    // 28 digits do not fit in a u64
+    let doubled = input.repeat(2);
+    assert_eq!(
+        analyse(&doubled).unwrap_err().to_string(),
+        r#"343:1: expected at most 19 blocks, found "inp w""#
+    );
+
+    // a single push block never popped
+    let unbalanced: String = input
+        .lines()
+        .take(18)
+        .map(|l| l.to_string() + "\n")
+        .collect();
+    assert_eq!(
+        analyse(&unbalanced).unwrap_err().to_string(),
+        "19:1: expected as many `div z 1` blocks as `div z 26` blocks, found nothing"
+    );
+}
+
+#[test]
+fn digit_search_agrees_with_the_analysis() {
+    let input = monad_input();
+    let program = parse_program(&input).unwrap();
+    let monad = analyse(&input).unwrap();
+    let search = DigitSearch::new(&program).unwrap();
+
+    assert_eq!(search.digits(), 14);
+    assert_eq!(search.largest(), Some(monad.largest()));
+    assert_eq!(search.smallest(), Some(monad.smallest()));
+}
+
+#[test]
+fn digit_search_handles_any_program() {
+    // accepts the numbers with a second digit larger than the first one by 3, w is still
+    // needed after the first block
+    let program = parse_program("inp w\ninp x\nmul x -1\nadd w x\nadd w 3\nadd z w\n").unwrap();
+    let search = DigitSearch::new(&program).unwrap();
+    assert_eq!(search.largest(), Some(69));
+    assert_eq!(search.smallest(), Some(14));
+
+    // crashes on a 9
+    let program = parse_program("inp w\nadd x w\nadd x -9\ndiv w x\n").unwrap();
+    assert_eq!(DigitSearch::new(&program).unwrap().largest(), Some(8));
+
+    // crashes unless the digit is 5
+    let program = parse_program("inp w\nadd x w\neql x 5\nmod w x\n").unwrap();
+    let search = DigitSearch::new(&program).unwrap();
+    assert_eq!(search.largest(), Some(5));
+    assert_eq!(search.smallest(), Some(5));
+
+    let program = parse_program("inp w\nadd z w\n").unwrap();
+    assert_eq!(DigitSearch::new(&program).unwrap().largest(), None);
+}
+
+#[test]
+fn digit_search_needs_model_numbers_fitting_in_64_bits() {
+    let program = parse_program(&"inp w\nadd z w\n".repeat(19)).unwrap();
+    assert_eq!(DigitSearch::new(&program).unwrap().digits(), 19);
+
+    let program = parse_program(&"inp w\nadd z w\n".repeat(20)).unwrap();
+    assert_eq!(
+        DigitSearch::new(&program).err(),
+        Some(TooManyDigits { at: 38 })
+    );
+}
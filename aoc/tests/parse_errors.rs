@@ -0,0 +1,95 @@
+use aoc::{find_solver, SolveError};
+
+fn error(day: u8, part: u8, input: &str) -> String {
+    let solver = find_solver(day, part).unwrap();
+    (solver.solve)(input)
+        .map(|answer| panic!("day {} part {} accepted {:?}: {}", day, part, input, answer))
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn errors_point_at_the_offending_token() {
+    assert_eq!(
+        error(1, 1, "199\n2OO\n"),
+        r#"2:1: expected a depth, found "2OO""#
+    );
+    assert_eq!(
+        error(2, 1, "forward 5\nbackward 2\n"),
+        r#"2:1: expected forward, down or up, found "backward""#
+    );
+    assert_eq!(
+        error(5, 1, "0,9 -> 5,9\n8,0 -> x,8\n"),
+        r#"2:8: expected a coordinate, found "x""#
+    );
+    assert_eq!(
+        error(17, 1, "target area: x=20..30, y=-10..-5x\n"),
+        r#"1:31: expected a number, found "-5x""#
+    );
+    assert_eq!(
+        error(25, 1, "v...>\n..#..\n"),
+        r##"2:3: expected one of > v ., found "#""##
+    );
+}
+
+#[test]
+fn errors_report_missing_tokens() {
+    assert_eq!(
+        error(17, 1, "target area: x=20..30\n"),
+        r#"1:14: expected x=<min>..<max>, y=<min>..<max>, found "x=20..30""#
+    );
+    assert_eq!(
+        error(22, 1, "on x=-20..26,y=-36..17\n"),
+        "1:23: expected a range for z, found nothing"
+    );
+    // every line is corrupted
+    assert_eq!(
+        error(10, 2, "(]\n{()()()>\n"),
+        "3:1: expected an incomplete line, found nothing"
+    );
+}
+
+#[test]
+fn errors_report_values_out_of_range() {
+    // A* needs every cell to cost at least 1
+    assert_eq!(
+        error(15, 1, "116\n138\n203\n"),
+        r#"3:2: expected a risk level from 1 to 9, found "0""#
+    );
+    assert_eq!(
+        error(5, 2, "0,9 -> 5,9\n0,0 -> 3,5\n"),
+        r#"2:1: expected a horizontal, vertical or 45° diagonal line, found "0,0 -> 3,5""#
+    );
+    let unclosed = "(".repeat(29);
+    assert_eq!(
+        error(10, 2, &format!("[]\n{}\n", unclosed)),
+        format!(
+            r#"2:1: expected an incomplete line with a score fitting in 64 bits, found "{}""#,
+            unclosed
+        )
+    );
+}
+
+#[test]
+fn errors_are_located_in_multi_file_inputs() {
+    let dir = std::env::temp_dir().join("aoc_parse_errors");
+    std::fs::create_dir_all(&dir).unwrap();
+    let points = dir.join("points");
+    let folds = dir.join("folds");
+    std::fs::write(&points, "6,10\n0,14\n").unwrap();
+    std::fs::write(&folds, "fold along y=7\nfold along z=5\n").unwrap();
+
+    let input = aoc::Input::read(&[&points, &folds]).unwrap();
+    let e = match (find_solver(13, 1).unwrap().solve)(&input.text) {
+        Err(SolveError::Parse(e)) => e,
+        result => panic!("{:?}", result),
+    };
+
+    assert_eq!(
+        input.locate(e).to_string(),
+        format!(
+            r#"{}:2:12: expected x=<n> or y=<n>, found "z=5""#,
+            folds.display()
+        )
+    );
+}
@@ -0,0 +1,286 @@
+use aoc::{workspace_dir, Input, SolveError};
+use day_19_v2::{
+    align, count_beacons, locate_scanners, map_beacons, Overlap, Point, Rotation, ScanError,
+    Transform, OVERLAP,
+};
+use std::collections::HashSet;
+
+fn scanners_input(scanners: &[Vec<Point>]) -> String {
+    scanners
+        .iter()
+        .enumerate()
+        .map(|(i, beacons)| {
+            let lines: Vec<String> = beacons
+                .iter()
+                .map(|(x, y, z)| format!("{},{},{}\n", x, y, z))
+                .collect();
+            format!("--- scanner {} ---\n{}", i, lines.concat())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Deterministic beacons spread over the range of a scanner
+fn beacons(seed: u64, count: usize) -> Vec<Point> {
+    let mut state = seed;
+    let mut coordinate = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % 1601) as i64 - 800
+    };
+    (0..count)
+        .map(|_| (coordinate(), coordinate(), coordinate()))
+        .collect()
+}
+
+#[test]
+fn there_are_24_rotations() {
+    let rotations = Rotation::all();
+
+    assert_eq!(rotations.len(), 24);
+    assert_eq!(rotations[0], Rotation::IDENTITY);
+    assert!(rotations.iter().all(|r| r.determinant() == 1));
+    let distinct: HashSet<_> = rotations.iter().map(|r| r.apply((1, 2, 3))).collect();
+    assert_eq!(distinct.len(), 24);
+}
+
+#[test]
+fn scanners_are_located_as_in_the_puzzle() {
+    let input = Input::read(&[workspace_dir().join("day_19_v2").join("test_input")]).unwrap();
+    let transforms = locate_scanners(&input.text, OVERLAP).unwrap();
+
+    let offsets: Vec<Point> = transforms.iter().map(|t| t.offset).collect();
+    assert_eq!(
+        offsets,
+        [
+            (0, 0, 0),
+            (68, -1246, -43),
+            (1105, -1205, 1229),
+            (-92, -2380, -20),
+            (-20, -1133, 1061)
+        ]
+    );
+    assert_eq!(transforms[0], Transform::IDENTITY);
+    assert_eq!(transforms[1].rotation.to_string(), "(-x, y, -z)");
+    assert_eq!(transforms[1].apply((686, 422, 578)), (-618, -824, -621));
+}
+
+#[test]
+fn scanners_are_aligned_whatever_the_deltas_between_beacons() {
+    let rotation = Rotation([[0, 0, -1], [1, 0, 0], [0, -1, 0]]);
+    let transform = Transform {
+        rotation,
+        offset: (1100, -70, 230),
+    };
+
+    // the first two beacons differ by (5, 5, -7), the same amount along two axes
+    let mut seen_by_1 = vec![(100, 200, 300), (105, 205, 293)];
+    seen_by_1.extend(beacons(1, 10));
+    let mut seen_by_0: Vec<Point> = seen_by_1.iter().map(|&p| transform.apply(p)).collect();
+    seen_by_0.extend(beacons(2, 4));
+    seen_by_1.extend(beacons(3, 4));
+
+    let matches: Vec<(usize, usize)> = (0..12).map(|i| (i, i)).collect();
+    assert_eq!(align(&seen_by_0, &seen_by_1, &matches, 12), Some(transform));
+    assert_eq!(
+        align(&seen_by_0, &seen_by_1, &matches[..11], 12),
+        Some(transform)
+    );
+    assert_eq!(
+        align(&seen_by_0[..11], &seen_by_1, &matches[..11], 12),
+        None
+    );
+
+    let input = scanners_input(&[seen_by_0, seen_by_1]);
+    assert_eq!(locate_scanners(&input, OVERLAP).unwrap()[1], transform);
+    for solve in [day_19_v1::solve_part1, day_19_v2::solve_part1] {
+        assert_eq!(solve(&input).unwrap().to_string(), "20");
+    }
+    for solve in [day_19_v1::solve_part2, day_19_v2::solve_part2] {
+        assert_eq!(solve(&input).unwrap().to_string(), "1400");
+    }
+}
+
+// Scanner 1 is rotated and moved, and shares `shared` beacons with scanner 0
+fn overlapping_scanners(shared: usize) -> (Vec<Point>, Vec<Point>, Transform) {
+    let transform = Transform {
+        rotation: Rotation([[0, 1, 0], [0, 0, 1], [1, 0, 0]]),
+        offset: (-20, 1133, -1061),
+    };
+
+    let mut seen_by_1 = beacons(4, shared);
+    let mut seen_by_0: Vec<Point> = seen_by_1.iter().map(|&p| transform.apply(p)).collect();
+    seen_by_0.extend(beacons(5, 6));
+    seen_by_1.extend(beacons(6, 6));
+    (seen_by_0, seen_by_1, transform)
+}
+
+#[test]
+fn scanners_overlap_with_at_least_the_threshold_of_beacons() {
+    let (seen_by_0, seen_by_1, transform) = overlapping_scanners(14);
+    let input = scanners_input(&[seen_by_0, seen_by_1]);
+
+    for overlap in [3, 12, 14] {
+        let overlap = Overlap::new(overlap).unwrap();
+        assert_eq!(
+            locate_scanners(&input, overlap).unwrap(),
+            [Transform::IDENTITY, transform]
+        );
+        assert_eq!(count_beacons(&input, overlap), Ok(14 + 6 + 6));
+    }
+    // more shared beacons than the threshold
+    assert_eq!(
+        day_19_v1::solve_part1(&input).unwrap().to_string(),
+        (14 + 6 + 6).to_string()
+    );
+    assert_eq!(
+        locate_scanners(&input, Overlap::new(15).unwrap())
+            .unwrap_err()
+            .to_string(),
+        "every scanner must share at least 15 beacons with scanner 0 or a scanner aligned \
+         with it, not scanner 1"
+    );
+}
+
+#[test]
+fn overlaps_give_the_rotation_of_the_scanners() {
+    assert_eq!(Overlap::new(2), None);
+    assert_eq!(Overlap::new(0), None);
+    assert_eq!(Overlap::new(3).map(Overlap::beacons), Some(3));
+    assert_eq!(OVERLAP.beacons(), 12);
+}
+
+#[test]
+fn unconnected_scanners_are_listed() {
+    let (seen_by_0, seen_by_1, _) = overlapping_scanners(12);
+    // 11 beacons in common with scanners 0 and 1
+    let mut seen_by_3 = seen_by_0[..11].to_vec();
+    seen_by_3.extend(beacons(8, 6));
+    let input = scanners_input(&[seen_by_0, seen_by_1, beacons(7, 20), seen_by_3]);
+
+    for solve in [day_19_v1::solve_part1, day_19_v2::solve_part1] {
+        assert_eq!(
+            solve(&input),
+            Err(SolveError::Evaluation(
+                "every scanner must share at least 12 beacons with scanner 0 or a scanner \
+                 aligned with it, not scanners 2 and 3"
+                    .into()
+            ))
+        );
+    }
+    let overlap = Overlap::new(11).unwrap();
+    assert_eq!(
+        count_beacons(&input, overlap),
+        Err(ScanError::Unconnected {
+            scanners: vec![2],
+            overlap
+        })
+    );
+
+    // scanners are reported by the numbers in their headers
+    let input = input
+        .replace("--- scanner 3 ---", "--- scanner 7 ---")
+        .replace("--- scanner 2 ---", "--- scanner 5 ---");
+    assert_eq!(
+        day_19_v1::solve_part1(&input).unwrap_err().to_string(),
+        "every scanner must share at least 12 beacons with scanner 0 or a scanner aligned \
+         with it, not scanners 5 and 7"
+    );
+    assert_eq!(
+        count_beacons(&input, overlap),
+        Err(ScanError::Unconnected {
+            scanners: vec![5],
+            overlap
+        })
+    );
+}
+
+#[test]
+fn scanners_are_numbered_in_increasing_order() {
+    for solve in [day_19_v1::solve_part1, day_19_v2::solve_part1] {
+        let error = |input: &str| solve(input).unwrap_err().to_string();
+        assert_eq!(
+            error("--- scanner 0 ---\n1,2,3\n\n--- scanner 0 ---\n4,5,6\n"),
+            r#"4:1: expected --- scanner <n> --- with n above 0, found "--- scanner 0 ---""#
+        );
+        assert_eq!(
+            error("--- scanner 1 ---\n1,2,3\n"),
+            r#"1:1: expected --- scanner 0 ---, found "--- scanner 1 ---""#
+        );
+        assert_eq!(
+            error("1,2,3\n"),
+            r#"1:1: expected --- scanner 0 ---, found "1,2,3""#
+        );
+        assert_eq!(
+            error("--- scanner 0 ---\n1,2,3\n\n4,5,6\n"),
+            r#"4:1: expected --- scanner <n> --- with n above 0, found "4,5,6""#
+        );
+    }
+    // a blank line between the scanners is optional
+    let (seen_by_0, seen_by_1, transform) = overlapping_scanners(12);
+    let input = scanners_input(&[seen_by_0, seen_by_1]).replace("\n\n", "\n");
+    assert_eq!(
+        locate_scanners(&input, OVERLAP).unwrap(),
+        [Transform::IDENTITY, transform]
+    );
+}
+
+#[test]
+fn beacon_maps_are_exported() {
+    let input = Input::read(&[workspace_dir().join("day_19_v2").join("test_input")]).unwrap();
+    let map = map_beacons(&input.text, OVERLAP).unwrap();
+
+    assert_eq!(map.scanners, locate_scanners(&input.text, OVERLAP).unwrap());
+    assert_eq!(map.links, [(0, 1), (1, 3), (1, 4), (4, 2)]);
+    assert_eq!(map.aligned_from(2), Some(4));
+    assert_eq!(map.aligned_from(0), None);
+    assert_eq!(map.beacons.len(), 79);
+    assert!(map.beacons.windows(2).all(|w| w[0] < w[1]));
+
+    // every beacon seen by a scanner is on the map once moved to the coordinates of scanner 0
+    let mut scanner = 0;
+    for line in input.text.lines() {
+        if line.starts_with("--- scanner") {
+            scanner = line.split(' ').nth(2).unwrap().parse().unwrap();
+        } else if !line.is_empty() {
+            let c: Vec<i64> = line.split(',').map(|c| c.parse().unwrap()).collect();
+            let beacon = map.scanners[scanner].apply((c[0], c[1], c[2]));
+            assert!(map.beacons.binary_search(&beacon).is_ok(), "{}", line);
+        }
+    }
+
+    let table = map.table().to_string();
+    assert!(table.starts_with(
+        "Scanner  Position             Rotation     Aligned from
+      0  (0, 0, 0)            (x, y, z)               -
+      1  (68, -1246, -43)     (-x, y, -z)             0
+      2  (1105, -1205, 1229)  (-x, z, y)              4
+      3  (-92, -2380, -20)    (-x, y, -z)             1
+      4  (-20, -1133, 1061)   (-y, -z, x)             1
+
+79 beacons:
+  (-892, 524, 684)
+"
+    ));
+    assert_eq!(table.lines().count(), 1 + 5 + 2 + 79);
+
+    let json = map.json().to_string();
+    assert!(json.starts_with(
+        r#"{
+  "scanners": [
+    {"index": 0, "position": [0, 0, 0], "rotation": [[1, 0, 0], [0, 1, 0], [0, 0, 1]], "aligned_from": null},
+    {"index": 1, "position": [68, -1246, -43], "rotation": [[-1, 0, 0], [0, 1, 0], [0, 0, -1]], "aligned_from": 0},
+"#
+    ));
+    assert!(json.contains("\n  \"links\": [[0, 1], [1, 3], [1, 4], [4, 2]],\n"));
+    assert!(json.ends_with("    [1994, -1805, 1792]\n  ]\n}\n"));
+
+    let csv = map.csv().to_string();
+    let records: Vec<&str> = csv.lines().collect();
+    assert_eq!(records.len(), 1 + 5 + 79);
+    assert_eq!(records[0], "kind,index,x,y,z,rotation,aligned_from");
+    assert_eq!(records[1], r#"scanner,0,0,0,0,"(x, y, z)","#);
+    assert_eq!(records[3], r#"scanner,2,1105,-1205,1229,"(-x, z, y)",4"#);
+    assert_eq!(records[6], "beacon,0,-892,524,684,,");
+}
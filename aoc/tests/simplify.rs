@@ -0,0 +1,97 @@
+use aoc::{workspace_dir, Input};
+use day_24::{model_digits, parse_program, simplify, Alu, Instruction, PseudoCode, Register};
+
+fn monad_program() -> Vec<Instruction> {
+    let input = Input::read(&[workspace_dir().join("day_24").join("input")]).unwrap();
+    parse_program(&input.text).unwrap()
+}
+
+fn z(program: &[Instruction], digits: &[i64]) -> Option<i64> {
+    let mut alu = Alu::default();
+    alu.run(program, digits.iter().copied()).ok()?;
+    Some(alu.get(Register::Z))
+}
+
+#[test]
+fn simplified_monad_computes_the_same_z() {
+    let program = monad_program();
+    let simplified = simplify(&program);
+    assert!(simplified.len() < program.len());
+    assert_eq!(
+        simplified
+            .iter()
+            .filter(|i| matches!(i, Instruction::Inp(_)))
+            .count(),
+        14
+    );
+
+    // a small linear congruential generator is enough to spread the model numbers
+    let mut seed = 0x2021_u64;
+    for _ in 0..500 {
+        let digits: Vec<i64> = (0..14)
+            .map(|_| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (seed >> 33) as i64 % 9 + 1
+            })
+            .collect();
+        assert_eq!(
+            z(&simplified, &digits),
+            z(&program, &digits),
+            "{:?}",
+            digits
+        );
+    }
+    assert_eq!(z(&simplified, &model_digits(99799212949967)), Some(0));
+}
+
+#[test]
+fn constant_comparisons_and_unused_values_are_removed() {
+    let program = parse_program(
+        "inp w\nmul x 0\nadd x 14\neql x w\neql x 0\nmul y 0\nadd y w\nmul y x\nadd z y\n",
+    )
+    .unwrap();
+    let simplified: Vec<String> = simplify(&program).iter().map(|i| i.to_string()).collect();
+
+    assert_eq!(simplified, ["inp w", "add y w", "add z y"]);
+    assert_eq!(
+        PseudoCode::new(&simplify(&program)).to_string(),
+        "w = input[0];\nz = w;\n"
+    );
+}
+
+#[test]
+fn crashing_instructions_are_kept() {
+    let program = parse_program("inp w\nadd x w\nadd x -5\nmod x 3\ndiv y x\n").unwrap();
+    let simplified: Vec<String> = simplify(&program).iter().map(|i| i.to_string()).collect();
+
+    assert_eq!(
+        simplified,
+        ["inp w", "add x w", "add x -5", "mod x 3", "div y x"]
+    );
+}
+
+#[test]
+fn pseudo_code_matches_the_hand_simplified_program() {
+    let code = PseudoCode::new(&simplify(&monad_program())).to_string();
+    let blocks: Vec<&str> = code.split("\n\n").collect();
+
+    assert_eq!(blocks.len(), 14);
+    assert_eq!(blocks[1], "w = input[1];\nz *= 26;\nz += w + 6;");
+    assert_eq!(
+        blocks[5],
+        "w = input[5];\nx = z;\nx %= 26;\nz /= 26;\nx += -7;\n\
+         if x != w {\n    z *= 26;\n    z += w + 4;\n}"
+    );
+}
+
+#[test]
+fn boolean_values_are_negated() {
+    let program = parse_program("inp w\ninp x\neql x w\neql x 0\nadd z x\n").unwrap();
+
+    assert_eq!(
+        PseudoCode::new(&program).to_string(),
+        "w = input[0];\n\nx = input[1];\nx = x != w;\nz = x;\n"
+    );
+}
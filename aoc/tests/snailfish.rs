@@ -0,0 +1,228 @@
+use aoc::{find_solver, workspace_dir, Input};
+use day_18_1::{parse_numbers, Action, FlatNumber, Node, Side, Trace, MAX_DEPTH};
+use day_18_2::{largest_sum, LargestSum};
+
+fn number(s: &str) -> Node {
+    s.parse().unwrap()
+}
+
+fn error(s: &str) -> String {
+    s.parse::<Node>().unwrap_err().to_string()
+}
+
+#[test]
+fn numbers_round_trip_through_display() {
+    let input = Input::read(&[workspace_dir().join("day_18.1").join("input")]).unwrap();
+    let printed: Vec<String> = parse_numbers(&input.text)
+        .unwrap()
+        .iter()
+        .map(|n| n.to_string())
+        .collect();
+
+    assert_eq!(printed.join("\n") + "\n", input.text);
+}
+
+#[test]
+fn multi_digit_values_and_whitespace_are_accepted() {
+    assert_eq!(
+        number(" [ [10, 3] ,4 ] "),
+        Node::Pair(
+            Box::new(Node::Pair(
+                Box::new(Node::Number(10)),
+                Box::new(Node::Number(3))
+            )),
+            Box::new(Node::Number(4))
+        )
+    );
+    assert_eq!(number("[[123,3],4]").to_string(), "[[123,3],4]");
+
+    let mut n = number("[[10,3],4]");
+    n.reduce();
+    assert_eq!(n, number("[[[5,5],3],4]"));
+
+    let mut n = number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]");
+    n.reduce();
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+}
+
+#[test]
+fn malformed_numbers_are_located() {
+    assert_eq!(error("[1,2"), "1:5: expected ], found nothing");
+    assert_eq!(error("[1;2]"), r#"1:3: expected ,, found ";""#);
+    assert_eq!(
+        error("[1,-2]"),
+        r#"1:4: expected a regular number or [, found "-""#
+    );
+    assert_eq!(
+        error("[1,2]]"),
+        r#"1:6: expected the end of the snailfish number, found "]""#
+    );
+    assert_eq!(
+        error("[1,99999999999]"),
+        r#"1:4: expected a regular number, found "99999999999""#
+    );
+    assert_eq!(
+        error(""),
+        "1:1: expected a regular number or [, found nothing"
+    );
+
+    let solver = find_solver(18, 1).unwrap();
+    assert_eq!(
+        (solver.solve)("[1,2]\n[[3,4], x]\n")
+            .unwrap_err()
+            .to_string(),
+        r#"2:9: expected a regular number or [, found "x""#
+    );
+}
+
+#[test]
+fn deeply_nested_numbers_are_rejected() {
+    let nested = |depth| format!("{}1{}", "[".repeat(depth), ",2]".repeat(depth));
+    assert_eq!(number(&nested(MAX_DEPTH)).to_string(), nested(MAX_DEPTH));
+
+    assert_eq!(
+        error(&nested(MAX_DEPTH + 1)),
+        format!(
+            r#"1:{}: expected pairs nested at most {} deep, found "[""#,
+            MAX_DEPTH + 1,
+            MAX_DEPTH
+        )
+    );
+    // reported before the recursion gets anywhere near the end of the stack
+    assert_eq!(error(&"[".repeat(200_000)), error(&nested(MAX_DEPTH + 1)));
+}
+
+#[test]
+fn reductions_are_traced_as_in_the_puzzle() {
+    let input = Input::read(&[workspace_dir().join("day_18.1").join("test_input_6")]).unwrap();
+    let numbers = parse_numbers(&input.text).unwrap();
+    let trace = Trace::add(numbers[0].clone(), numbers[1].clone());
+
+    assert_eq!(
+        trace.to_string(),
+        "after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[15,[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+after explode:  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+"
+    );
+
+    use Side::{Left as L, Right as R};
+    assert_eq!(
+        trace.steps[0].action,
+        Action::Explode {
+            path: vec![L, L, L, L],
+            pair: (4, 3)
+        }
+    );
+    assert_eq!(
+        trace.steps[2].action,
+        Action::Split {
+            path: vec![L, R, L],
+            value: 15
+        }
+    );
+    let actions: Vec<String> = trace.steps.iter().map(|s| s.action.to_string()).collect();
+    assert_eq!(
+        actions,
+        [
+            "explode [4,3] at LLLL",
+            "explode [8,4] at LRRL",
+            "split 15 at LRL",
+            "split 13 at LRRR",
+            "explode [6,7] at LRRR",
+        ]
+    );
+    assert_eq!(trace.result(), &number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+}
+
+#[test]
+fn traced_sums_match_the_reduced_ones() {
+    let input = Input::read(&[workspace_dir().join("day_18.1").join("test_input_4")]).unwrap();
+    let numbers = parse_numbers(&input.text).unwrap();
+
+    let mut sum = numbers[0].clone();
+    for n in &numbers[1..] {
+        let trace = Trace::add(sum.clone(), n.clone());
+        sum = sum + n.clone();
+        sum.reduce();
+        assert_eq!(trace.result(), &sum);
+    }
+    assert_eq!(sum.magnitude(), 4140);
+
+    let mut ten = number("10");
+    assert_eq!(
+        ten.reduce_step().map(|a| a.to_string()).as_deref(),
+        Some("split 10 at the root")
+    );
+    assert_eq!(ten.reduce_step(), None);
+}
+
+#[test]
+fn flat_numbers_reduce_like_boxed_ones() {
+    for name in ["input", "test_input_3", "test_input_4", "test_input_6"] {
+        let input = Input::read(&[workspace_dir().join("day_18.1").join(name)]).unwrap();
+        let numbers = parse_numbers(&input.text).unwrap();
+
+        let mut boxed = numbers[0].clone();
+        let mut flat = FlatNumber::from(&numbers[0]);
+        for n in &numbers[1..] {
+            boxed = boxed + n.clone();
+            boxed.reduce();
+            flat = flat + FlatNumber::from(n);
+            flat.reduce();
+            assert_eq!(Node::from(&flat), boxed, "{}", name);
+        }
+        assert_eq!(flat.magnitude(), boxed.magnitude(), "{}", name);
+    }
+
+    // more deeply nested than sums of reduced numbers
+    for s in [
+        "[[[[[[1,2],3],4],5],6],7]",
+        "[7,[6,[5,[4,[3,[2,[1,9]]]]]]]",
+        "[[[[[4,[3,[20,1]]],4],4],[7,[[8,4],9]]],[1,1]]",
+        "[[10,3],4]",
+        "42",
+    ] {
+        let mut boxed = number(s);
+        let mut flat = FlatNumber::from(&boxed);
+        assert_eq!(flat.to_string(), s);
+        boxed.reduce();
+        flat.reduce();
+        assert_eq!(flat.to_string(), boxed.to_string(), "{}", s);
+        assert_eq!(flat.magnitude(), boxed.magnitude(), "{}", s);
+    }
+}
+
+#[test]
+fn largest_sums_are_found_among_all_ordered_pairs() {
+    let input = Input::read(&[workspace_dir().join("day_18.2").join("test_input_7")]).unwrap();
+    let numbers = parse_numbers(&input.text).unwrap();
+
+    assert_eq!(
+        largest_sum(&numbers),
+        Some(LargestSum {
+            left: 8,
+            right: 0,
+            sum: number("[[[[7,8],[6,6]],[[6,0],[7,7]]],[[[7,8],[8,8]],[[7,9],[0,6]]]]"),
+            magnitude: 3993
+        })
+    );
+
+    // the second to last number is a left operand too
+    let numbers = parse_numbers("[1,1]\n[9,9]\n[8,8]\n").unwrap();
+    let largest = largest_sum(&numbers).unwrap();
+    assert_eq!(
+        (largest.left, largest.right, largest.magnitude),
+        (1, 2, 215)
+    );
+
+    assert_eq!(largest_sum(&numbers[..1]), None);
+    let solver = find_solver(18, 2).unwrap();
+    assert_eq!(
+        (solver.solve)("[1,1]\n").unwrap_err().to_string(),
+        "2:1: expected at least two snailfish numbers, found nothing"
+    );
+}
@@ -0,0 +1,101 @@
+use aoc::{workspace_dir, Input};
+use aoc_common::Search;
+use day_15_2_v2::TiledMap;
+
+fn bundled(file: &str) -> String {
+    Input::read(&[workspace_dir().join("day_15.2_v2").join(file)])
+        .unwrap()
+        .text
+}
+
+#[test]
+fn tiled_map_agrees_with_the_materialised_grids() {
+    for file in ["test_input", "input"] {
+        let input = bundled(file);
+
+        let untiled = TiledMap::parse(&input, 1).unwrap();
+        assert_eq!(
+            untiled.find_path(Search::AStar).cost,
+            day_15_1::find_path(&input, Search::AStar).unwrap().cost
+        );
+
+        let tiled = TiledMap::parse(&input, 5).unwrap();
+        assert_eq!(
+            tiled.find_path(Search::AStar).cost,
+            day_15_2_v1::find_path(&input, Search::Dijkstra)
+                .unwrap()
+                .cost
+        );
+    }
+}
+
+#[test]
+fn risk_levels_start_at_1() {
+    let error = |input: &str| TiledMap::parse(input, 5).err().unwrap().to_string();
+    assert_eq!(
+        error("19\n90\n"),
+        r#"2:2: expected a risk level from 1 to 9, found "0""#
+    );
+    assert_eq!(
+        day_15_2_v1::solve_part2("19\n90\n")
+            .unwrap_err()
+            .to_string(),
+        r#"2:2: expected a risk level from 1 to 9, found "0""#
+    );
+}
+
+#[test]
+fn any_tiling_factor() {
+    let map = TiledMap::parse(&bundled("test_input"), 7).unwrap();
+
+    assert_eq!(map.size(), (70, 70));
+    assert_eq!(map.weight((69, 69)), (1 + 12 - 1) % 9 + 1);
+    assert_eq!(
+        map.find_path(Search::AStar).cost,
+        map.find_path(Search::Dijkstra).cost
+    );
+}
+
+fn strip_colours(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn path_view_highlights_the_path_over_the_map() {
+    let input = bundled("test_input");
+    let map = TiledMap::parse(&input, 1).unwrap();
+    let path = map.find_path(Search::AStar);
+
+    let rendered = map.view(&path).to_string();
+    assert_eq!(strip_colours(&rendered), input);
+    assert_eq!(
+        rendered.matches("\u{1b}[1;31m").count(),
+        path.positions.len()
+    );
+}
+
+#[test]
+fn path_view_exports_ppm() {
+    let map = TiledMap::parse(&bundled("test_input"), 2).unwrap();
+    let path = map.find_path(Search::AStar);
+
+    let mut ppm = vec![];
+    map.view(&path).write_ppm(&mut ppm).unwrap();
+
+    let header = b"P6\n20 20\n255\n";
+    assert!(ppm.starts_with(header));
+    let pixels = &ppm[header.len()..];
+    assert_eq!(pixels.len(), 3 * 20 * 20);
+    // both corners are on the path
+    assert_eq!(pixels[..3], [255, 0, 0]);
+    assert_eq!(pixels[pixels.len() - 3..], [255, 0, 0]);
+}
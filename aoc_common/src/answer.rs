@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture to be read by a human, one string per row (e.g. day 13).
+    Render(Vec<String>),
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Answer::Integer(v as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(v) => write!(f, "{}", v),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Render(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
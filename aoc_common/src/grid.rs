@@ -0,0 +1,235 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// Kept apart from the cells so that neighbour iterators do not borrow the grid,
+// and can be used while it is being mutated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Shape {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) wrapping: bool,
+}
+
+impl Shape {
+    fn locate(&self, (x, y): (isize, isize)) -> Option<(usize, usize)> {
+        if self.wrapping {
+            Some((
+                x.rem_euclid(self.width as isize) as usize,
+                y.rem_euclid(self.height as isize) as usize,
+            ))
+        } else if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        self.locate((x as isize + dx, y as isize + dy))
+    }
+
+    pub(crate) fn neighbours4(self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    fn neighbours8(self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+}
+
+/// A rectangular grid, indexed by `(x, y)` positions with `(0, 0)` in the top-left corner.
+///
+/// By default, positions outside of the grid do not exist. In wrapping mode, the grid is a
+/// torus: leaving it on one edge comes back on the opposite one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    shape: Shape,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(width, height, |_| value.clone())
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        Grid {
+            shape: Shape {
+                width,
+                height,
+                wrapping: false,
+            },
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(&mut f)
+                .collect(),
+        }
+    }
+
+    /// Parses a map with one character per cell and one row per line. `cell` returns `None`
+    /// for characters that are not `expected`.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(ParseError::at_end(input, expected));
+        }
+
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for l in input.lines() {
+            if l.chars().count() != width {
+                return Err(ParseError::new(
+                    input,
+                    l,
+                    format!("a row of {} cells", width),
+                ));
+            }
+            for (i, c) in l.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::new(input, &l[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            shape: Shape {
+                width,
+                height,
+                wrapping: false,
+            },
+            cells,
+        })
+    }
+
+    /// Switches the grid to wrapping mode.
+    pub fn wrapping(mut self) -> Self {
+        self.shape.wrapping = true;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.shape.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.shape.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.shape
+            .locate((pos.0 as isize, pos.1 as isize))
+            .map(|p| &self[p])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.shape
+            .locate((pos.0 as isize, pos.1 as isize))
+            .map(move |p| &mut self[p])
+    }
+
+    /// Value at a possibly out-of-bounds position, `default` if there is no such cell.
+    pub fn get_or(&self, pos: (isize, isize), default: T) -> T
+    where
+        T: Copy,
+    {
+        self.shape.locate(pos).map_or(default, |p| self[p])
+    }
+
+    /// Position reached by moving by `(dx, dy)` from `pos`, if it is in the grid.
+    pub fn offset(&self, pos: (usize, usize), delta: (isize, isize)) -> Option<(usize, usize)> {
+        self.shape.offset(pos, delta)
+    }
+
+    /// Up, left, right and down neighbours of `pos`.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.shape.neighbours4(pos)
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.shape.neighbours8(pos)
+    }
+
+    /// All the positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let Shape { width, height, .. } = self.shape;
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.shape.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            shape: self.shape,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.shape.width, "x = {} is out of the grid", x);
+        &self.cells[y * self.shape.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.shape.width, "x = {} is out of the grid", x);
+        &mut self.cells[y * self.shape.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use std::{fs, io, path::Path};
+
+use crate::ParseError;
+
+/// Puzzle input read from one or several files.
+///
+/// Puzzles split over several files (e.g. day 13) are joined into the format the solvers
+/// expect, one section per file separated by a blank line.
+pub struct Input {
+    pub text: String,
+    // file name and first line of each section
+    files: Vec<(String, usize)>,
+}
+
+impl Input {
+    pub fn read<P: AsRef<Path>>(paths: &[P]) -> io::Result<Self> {
+        let mut text = String::new();
+        let mut files = Vec::new();
+        let mut line = 1;
+
+        for p in paths {
+            let content = fs::read_to_string(p).map_err(|e| {
+                io::Error::new(e.kind(), format!("{}: {}", p.as_ref().display(), e))
+            })?;
+            let content = content.trim_end();
+
+            if !text.is_empty() {
+                text += "\n\n";
+                line += 1;
+            }
+            files.push((p.as_ref().display().to_string(), line));
+            text += content;
+            line += content.lines().count();
+        }
+        text += "\n";
+
+        Ok(Input { text, files })
+    }
+
+    /// Fills in the file name of an error found in `self.text`, and makes its line
+    /// number relative to that file.
+    pub fn locate(&self, e: ParseError) -> ParseError {
+        match self.files.iter().rev().find(|(_, start)| *start <= e.line) {
+            Some((file, start)) => ParseError {
+                file: Some(file.clone()),
+                line: e.line - start + 1,
+                ..e
+            },
+            None => e,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod answer;
+mod grid;
+mod input;
+mod parse;
+mod path;
+mod solve;
+mod transform;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use input::Input;
+pub use parse::{parse_digit_grid, parse_lines, parse_risk_grid, parse_token, ParseError};
+pub use path::{shortest_path, Path, Search};
+pub use solve::SolveError;
+pub use transform::{align, Point, Rotation, Transform};
@@ -0,0 +1,108 @@
+use std::{fmt, str::FromStr};
+
+use crate::Grid;
+
+/// A malformed puzzle input, with the position of the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// `token` must be a slice of `context` (as returned by `lines`, `split`, `trim`, ...)
+    /// so that its position can be recovered from the pointers.
+    pub fn new(context: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(context.as_ptr() as usize)
+            .filter(|o| *o <= context.len())
+            .unwrap_or(0);
+        let before = &context[..offset];
+
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap().chars().count() + 1,
+            expected: expected.into(),
+            found: token.to_string(),
+        }
+    }
+
+    /// Error for something missing at the end of `context`.
+    pub fn at_end(context: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(context, &context[context.len()..], expected)
+    }
+
+    /// Translates the position of an error relative to `context`, a slice of `input`,
+    /// into a position relative to `input`.
+    pub fn within(self, input: &str, context: &str) -> Self {
+        let base = ParseError::new(input, context, "");
+
+        ParseError {
+            line: base.line + self.line - 1,
+            column: if self.line == 1 {
+                base.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+
+        match self.found.lines().next() {
+            None | Some("") => write!(f, ", found nothing"),
+            Some(found) => write!(f, ", found \"{}\"", found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `context`, reporting `expected` on failure.
+pub fn parse_token<T: FromStr>(
+    context: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(context, token, expected))
+}
+
+/// Parses every line of `input` with `T::from_str`.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.parse().map_err(|e: ParseError| e.within(input, l)))
+        .collect()
+}
+
+/// Parses a rectangle of single digits, one row per line.
+pub fn parse_digit_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8), "a digit")
+}
+
+/// Parses a rectangle of risk levels from 1 to 9, one row per line. Every cell costs at
+/// least 1 to enter, as `Search::AStar` requires.
+pub fn parse_risk_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(
+        input,
+        |c| c.to_digit(10).filter(|&d| d > 0).map(|d| d as u8),
+        "a risk level from 1 to 9",
+    )
+}
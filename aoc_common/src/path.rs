@@ -0,0 +1,83 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::grid::Shape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Search {
+    Dijkstra,
+    /// Guided by the Manhattan distance to the goal, which assumes every step costs at least 1.
+    AStar,
+}
+
+/// A path between two cells of a grid and the sum of the weights of the cells entered along it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: u32,
+    /// From the start to the goal, both included.
+    pub positions: Vec<(usize, usize)>,
+    /// Number of cells whose shortest path was settled during the search.
+    pub explored: usize,
+}
+
+/// Cheapest path from `start` to `goal` in a `width` x `height` grid, moving orthogonally.
+/// Entering the cell at `pos` costs `weight(pos)`, so weights are only computed for the
+/// cells the search reaches.
+pub fn shortest_path(
+    (width, height): (usize, usize),
+    weight: impl Fn((usize, usize)) -> u32,
+    start: (usize, usize),
+    goal: (usize, usize),
+    search: Search,
+) -> Option<Path> {
+    let shape = Shape {
+        width,
+        height,
+        wrapping: false,
+    };
+    let index = |(x, y): (usize, usize)| y * width + x;
+    let heuristic = |(x, y): (usize, usize)| match search {
+        Search::Dijkstra => 0,
+        Search::AStar => (goal.0.abs_diff(x) + goal.1.abs_diff(y)) as u32,
+    };
+
+    let mut costs = vec![u32::MAX; width * height];
+    let mut previous = vec![None; width * height];
+    let mut explored = 0;
+    let mut queue = BinaryHeap::new();
+
+    costs[index(start)] = 0;
+    queue.push(Reverse((heuristic(start), 0, start)));
+
+    while let Some(Reverse((_, cost, pos))) = queue.pop() {
+        // stale entry, a cheaper path to pos was already settled
+        if cost > costs[index(pos)] {
+            continue;
+        }
+        explored += 1;
+
+        if pos == goal {
+            let mut positions = vec![goal];
+            while let Some(p) = previous[index(positions[positions.len() - 1])] {
+                positions.push(p);
+            }
+            positions.reverse();
+
+            return Some(Path {
+                cost,
+                positions,
+                explored,
+            });
+        }
+
+        for n in shape.neighbours4(pos) {
+            let new_cost = cost + weight(n);
+            if new_cost < costs[index(n)] {
+                costs[index(n)] = new_cost;
+                previous[index(n)] = Some(pos);
+                queue.push(Reverse((new_cost + heuristic(n), new_cost, n)));
+            }
+        }
+    }
+
+    None
+}
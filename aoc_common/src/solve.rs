@@ -0,0 +1,30 @@
+use std::fmt;
+
+use crate::ParseError;
+
+/// Why a part of a puzzle has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well-formed, but the answer cannot be computed from it (e.g. it does
+    /// not fit in an integer).
+    Evaluation(String),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Evaluation(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
@@ -0,0 +1,132 @@
+use std::{collections::HashMap, fmt};
+
+/// A point of the 3D space, `(x, y, z)`.
+pub type Point = (i64, i64, i64);
+
+/// A rotation of the scanner axes: a signed permutation matrix with determinant 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation(pub [[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// The 24 orientations a scanner can have, starting with the identity.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+
+        let mut rotations = Vec::with_capacity(24);
+        for permutation in PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, &column) in permutation.iter().enumerate() {
+                    matrix[row][column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+
+                let rotation = Rotation(matrix);
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn determinant(&self) -> i64 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    pub fn apply(&self, (x, y, z): Point) -> Point {
+        let row = |r: &[i64; 3]| r[0] * x + r[1] * y + r[2] * z;
+        (row(&self.0[0]), row(&self.0[1]), row(&self.0[2]))
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the coordinate each axis is taken from, e.g. `(-y, x, z)`
+        let axes: Vec<String> = self
+            .0
+            .iter()
+            .map(|row| {
+                let column = row.iter().position(|&c| c != 0).unwrap();
+                let sign = if row[column] < 0 { "-" } else { "" };
+                format!("{}{}", sign, ["x", "y", "z"][column])
+            })
+            .collect();
+        write!(f, "({})", axes.join(", "))
+    }
+}
+
+/// Maps the coordinates of a scanner to the ones of a reference scanner: the points are
+/// rotated, then moved by `offset`, which is the position of the scanner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transform {
+    pub rotation: Rotation,
+    pub offset: Point,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        rotation: Rotation::IDENTITY,
+        offset: (0, 0, 0),
+    };
+
+    pub fn apply(&self, p: Point) -> Point {
+        let (x, y, z) = self.rotation.apply(p);
+        (x + self.offset.0, y + self.offset.1, z + self.offset.2)
+    }
+}
+
+/// Finds how to map `points` onto `base`, given pairs of indices `(i, j)` of points that
+/// are likely the same (`base[i]` and `points[j]`).
+///
+/// For every rotation, each pair votes for the offset it implies, and the most voted
+/// rotation and offset win. The transform is only returned if at least `overlap` of the
+/// transformed points are then found in `base`, so a few wrong pairs do not matter.
+pub fn align(
+    base: &[Point],
+    points: &[Point],
+    matches: &[(usize, usize)],
+    overlap: usize,
+) -> Option<Transform> {
+    let mut best: Option<(usize, Transform)> = None;
+
+    for rotation in Rotation::all() {
+        let mut votes: HashMap<Point, usize> = HashMap::new();
+        for &(i, j) in matches {
+            let (x, y, z) = rotation.apply(points[j]);
+            let offset = (base[i].0 - x, base[i].1 - y, base[i].2 - z);
+            *votes.entry(offset).or_default() += 1;
+        }
+
+        if let Some((&offset, &count)) = votes
+            .iter()
+            .max_by_key(|&(&offset, &count)| (count, offset))
+        {
+            if best.is_none_or(|(c, _)| count > c) {
+                best = Some((count, Transform { rotation, offset }));
+            }
+        }
+    }
+
+    let (_, transform) = best?;
+    let common = points
+        .iter()
+        .filter(|&&p| base.contains(&transform.apply(p)))
+        .count();
+    if common >= overlap {
+        Some(transform)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::{parse_digit_grid, Grid};
+
+const MAP: &str = "123\n456\n789\n";
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = parse_digit_grid(MAP).unwrap();
+
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(
+        grid.neighbours8((2, 2)).map(|p| grid[p]).sum::<u8>(),
+        5 + 6 + 8
+    );
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+}
+
+#[test]
+fn wrapping_grids_are_toroidal() {
+    let grid = parse_digit_grid(MAP).unwrap().wrapping();
+
+    assert_eq!(grid.offset((2, 0), (1, -1)), Some((0, 2)));
+    assert_eq!(
+        grid.neighbours4((0, 0))
+            .map(|p| grid[p])
+            .collect::<Vec<_>>(),
+        [7, 3, 2, 4]
+    );
+    assert_eq!(grid.get_or((-1, -1), 0), 9);
+}
+
+#[test]
+fn out_of_bounds_reads_default() {
+    let grid = parse_digit_grid(MAP).unwrap();
+
+    assert_eq!(grid.get_or((-1, 1), 0), 0);
+    assert_eq!(grid.get_or((2, 1), 0), 6);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.offset((0, 2), (0, 1)), None);
+}
+
+#[test]
+fn display_round_trips() {
+    let grid = Grid::parse(".#.\n##.\n", |c| Some(c == '#'), ". or #").unwrap();
+    let rendered = grid.map(|&b| if b { '#' } else { '.' }).to_string();
+
+    assert_eq!(rendered, ".#.\n##.");
+    assert_eq!(parse_digit_grid(MAP).unwrap().to_string(), MAP.trim_end());
+}
+
+#[test]
+fn ragged_maps_are_rejected() {
+    let e = parse_digit_grid("123\n45\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        r#"2:1: expected a row of 3 cells, found "45""#
+    );
+
+    let e = parse_digit_grid("").unwrap_err();
+    assert_eq!(e.to_string(), "1:1: expected a digit, found nothing");
+}
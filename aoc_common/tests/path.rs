@@ -0,0 +1,63 @@
+use aoc_common::{parse_digit_grid, shortest_path, Search};
+
+const RISK_MAP: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+#[test]
+fn paths_are_consistent_with_their_cost() {
+    let grid = parse_digit_grid(RISK_MAP).unwrap();
+
+    for search in [Search::Dijkstra, Search::AStar] {
+        let path = shortest_path(
+            (grid.width(), grid.height()),
+            |p| grid[p] as u32,
+            (0, 0),
+            (9, 9),
+            search,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.positions.first(), Some(&(0, 0)));
+        assert_eq!(path.positions.last(), Some(&(9, 9)));
+        assert!(path
+            .positions
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+        assert_eq!(
+            path.positions[1..]
+                .iter()
+                .map(|&p| grid[p] as u32)
+                .sum::<u32>(),
+            path.cost
+        );
+    }
+}
+
+#[test]
+fn a_star_explores_less() {
+    let grid = parse_digit_grid(RISK_MAP).unwrap();
+    let explored = |search| {
+        shortest_path(
+            (grid.width(), grid.height()),
+            |p| grid[p] as u32,
+            (0, 0),
+            (9, 0),
+            search,
+        )
+        .unwrap()
+        .explored
+    };
+
+    assert!(explored(Search::AStar) < explored(Search::Dijkstra));
+}
@@ -0,0 +1,22 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let depths = input
+        .lines()
+        .map(|line| parse_token::<i32>(input, line, "a depth"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(depths
+        .into_iter()
+        .fold((0u32, None), |(mut count, prev), val| match prev {
+            None => (count, Some(val)),
+            Some(prev) => {
+                if prev < val {
+                    count += 1u32;
+                };
+                (count, Some(val))
+            }
+        })
+        .0
+        .into())
+}
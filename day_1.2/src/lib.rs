@@ -0,0 +1,25 @@
+use aoc_common::{parse_token, Answer, ParseError};
+use itertools::Itertools;
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let depths = input
+        .lines()
+        .map(|line| parse_token::<i32>(input, line, "a depth"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(depths
+        .into_iter()
+        .tuple_windows()
+        .map(|(x, y, z)| x + y + z)
+        .fold((0u32, None), |(mut count, prev), val| match prev {
+            None => (count, Some(val)),
+            Some(prev) => {
+                if prev < val {
+                    count += 1u32;
+                };
+                (count, Some(val))
+            }
+        })
+        .0
+        .into())
+}
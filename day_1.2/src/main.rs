@@ -1,32 +1,16 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::{env, process};
 
-use itertools::Itertools;
+use aoc_common::Input;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
+    let input = Input::read(&args[1..2]).unwrap();
 
-    let filename = &args[1];
-    // Open the file in read-only mode (ignoring errors).
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let count = reader
-        .lines()
-        .map(|line| line.unwrap().parse::<i32>().unwrap())
-        .tuple_windows()
-        .map(|(x, y, z)| x + y + z)
-        .fold((0u32, None), |(mut count, prev), val| match prev {
-            None => (count, Some(val)),
-            Some(prev) => {
-                if prev < val {
-                    count += 1u32;
-                };
-                (count, Some(val))
-            }
-        })
-        .0;
-
-    println!("Increases count: {}", count);
+    match day_1_2::solve_part2(&input.text) {
+        Ok(answer) => println!("Increases count: {}", answer),
+        Err(e) => {
+            eprintln!("{}", input.locate(e));
+            process::exit(1);
+        }
+    }
 }
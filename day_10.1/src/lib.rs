@@ -0,0 +1,54 @@
+use aoc_common::{Answer, ParseError};
+use std::collections::VecDeque;
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    input
+        .lines()
+        .map(|l| {
+            let mut queue = VecDeque::new();
+            l.char_indices()
+                .map(|(i, c)| match c {
+                    '(' | '<' | '[' | '{' => {
+                        queue.push_back(c);
+                        Ok(None)
+                    }
+                    ')' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '(' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(3))
+                    }
+                    '>' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '<' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(25137))
+                    }
+                    ']' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '[' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(57))
+                    }
+                    '}' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '{' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(1197))
+                    }
+                    _ => Err(ParseError::new(input, &l[i..i + c.len_utf8()], "a bracket")),
+                })
+                .find_map(Result::transpose)
+                .unwrap_or(Ok(0u64))
+        })
+        .sum::<Result<u64, _>>()
+        .map(Answer::from)
+}
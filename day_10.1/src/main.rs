@@ -1,65 +1,16 @@
-use std::{
-    collections::VecDeque,
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::{env, process};
+
+use aoc_common::Input;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    // Open the file in read-only mode (ignoring errors).
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    let score = reader
-        .lines()
-        .map(|l| {
-            let mut queue = VecDeque::new();
-            l.unwrap()
-                .chars()
-                .find_map(|c| match c {
-                    '(' | '<' | '[' | '{' => {
-                        queue.push_back(c);
-                        None
-                    }
-                    ')' => {
-                        if let Some(d) = queue.pop_back() {
-                            if d == '(' {
-                                return None;
-                            }
-                        }
-                        Some(3)
-                    }
-                    '>' => {
-                        if let Some(d) = queue.pop_back() {
-                            if d == '<' {
-                                return None;
-                            }
-                        }
-                        Some(25137)
-                    }
-                    ']' => {
-                        if let Some(d) = queue.pop_back() {
-                            if d == '[' {
-                                return None;
-                            }
-                        }
-                        Some(57)
-                    }
-                    '}' => {
-                        if let Some(d) = queue.pop_back() {
-                            if d == '{' {
-                                return None;
-                            }
-                        }
-                        Some(1197)
-                    }
-                    _ => unimplemented!(),
-                })
-                .unwrap_or(0u64)
-        })
-        .sum::<u64>();
+    let input = Input::read(&args[1..2]).unwrap();
 
-    println!("Score: {}", score);
+    match day_10_1::solve_part1(&input.text) {
+        Ok(answer) => println!("Score: {}", answer),
+        Err(e) => {
+            eprintln!("{}", input.locate(e));
+            process::exit(1);
+        }
+    }
 }
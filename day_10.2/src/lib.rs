@@ -0,0 +1,86 @@
+use aoc_common::{Answer, ParseError};
+use std::collections::VecDeque;
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let mut scores: Vec<u64> = input
+        .lines()
+        .filter_map(|l| {
+            let mut queue = VecDeque::new();
+            let line_score = l
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '(' | '<' | '[' | '{' => {
+                        queue.push_back(c);
+                        Ok(None)
+                    }
+                    ')' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '(' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(3))
+                    }
+                    '>' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '<' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(25137))
+                    }
+                    ']' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '[' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(57))
+                    }
+                    '}' => {
+                        if let Some(d) = queue.pop_back() {
+                            if d == '{' {
+                                return Ok(None);
+                            }
+                        }
+                        Ok(Some(1197))
+                    }
+                    _ => Err(ParseError::new(input, &l[i..i + c.len_utf8()], "a bracket")),
+                })
+                .find_map(Result::transpose);
+            match line_score {
+                None => Some(Ok((l, queue))),
+                Some(Ok(_)) => None,
+                Some(Err(e)) => Some(Err(e)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|(l, queue)| {
+            queue
+                .into_iter()
+                .rev()
+                .map(|c| match c {
+                    '(' => 1,
+                    '<' => 4,
+                    '[' => 2,
+                    '{' => 3,
+                    _ => unimplemented!(),
+                })
+                .try_fold(0u64, |acc, v| acc.checked_mul(5)?.checked_add(v))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        input,
+                        l,
+                        "an incomplete line with a score fitting in 64 bits",
+                    )
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    if scores.is_empty() {
+        return Err(ParseError::at_end(input, "an incomplete line"));
+    }
+    scores.sort_unstable();
+    Ok(scores[scores.len() / 2].into())
+}
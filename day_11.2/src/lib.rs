@@ -0,0 +1,72 @@
+use aoc_common::{parse_digit_grid, Answer, Grid, ParseError};
+
+enum Octopus {
+    Flashed(),
+    Idle(u8),
+}
+
+impl Octopus {
+    fn reset(&mut self) {
+        *self = Octopus::Idle(0)
+    }
+    fn will_flash(&self) -> bool {
+        matches!(self, Octopus::Idle(v) if *v > 9)
+    }
+    fn increment(&mut self) {
+        if let Octopus::Idle(v) = self {
+            *v += 1
+        }
+    }
+}
+
+fn increment_all(grid: &mut Grid<Octopus>) {
+    grid.iter_mut().for_each(|o| o.increment());
+}
+fn reset_all_flashed(grid: &mut Grid<Octopus>) -> usize {
+    grid.iter_mut()
+        .map(|o| match o {
+            Octopus::Flashed() => {
+                o.reset();
+                1
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn increment_surroundings(grid: &mut Grid<Octopus>, pos: (usize, usize)) {
+    for n in grid.neighbours8(pos) {
+        grid[n].increment();
+    }
+}
+
+fn get_flashing(grid: &Grid<Octopus>) -> Vec<(usize, usize)> {
+    grid.positions().filter(|&p| grid[p].will_flash()).collect()
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let mut grid = parse_digit_grid(input)?.map(|&v| Octopus::Idle(v));
+
+    Ok((1..)
+        .find(|_| {
+            increment_all(&mut grid);
+
+            loop {
+                let flashing = get_flashing(&grid);
+                if !flashing.is_empty() {
+                    flashing.iter().for_each(|&p| {
+                        increment_surroundings(&mut grid, p);
+                        grid[p] = Octopus::Flashed();
+                    });
+                } else {
+                    break;
+                }
+            }
+
+            let step_flashes = reset_all_flashed(&mut grid);
+
+            step_flashes == grid.width() * grid.height()
+        })
+        .unwrap()
+        .into())
+}
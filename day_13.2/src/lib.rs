@@ -0,0 +1,97 @@
+use aoc_common::{parse_token, Answer, ParseError};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl Point {
+    fn fold(self, fold: &Fold) -> Self {
+        match fold {
+            Fold::X(x0) => Point {
+                x: if self.x > *x0 {
+                    2 * x0 - self.x
+                } else {
+                    self.x
+                },
+                y: self.y,
+            },
+            Fold::Y(y0) => Point {
+                x: self.x,
+                y: if self.y > *y0 {
+                    2 * y0 - self.y
+                } else {
+                    self.y
+                },
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Fold {
+    X(u32),
+    Y(u32),
+}
+
+fn render_points(points: &HashSet<Point>) -> Vec<String> {
+    let max_x = points.iter().map(|p| p.x).max().unwrap() as usize;
+    let max_y = points.iter().map(|p| p.y).max().unwrap() as usize;
+
+    let mut table = vec![vec!['.'; max_x + 1]; max_y + 1];
+
+    points
+        .iter()
+        .for_each(|p| table[p.y as usize][p.x as usize] = '#');
+
+    table
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect()
+}
+fn parse_input(input: &str) -> Result<(HashSet<Point>, Vec<Fold>), ParseError> {
+    let (points_str, folds_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "a blank line followed by the folds"))?;
+
+    let points = points_str
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(input, line, "a point x,y"))?;
+            Ok(Point {
+                x: parse_token(input, x, "a coordinate")?,
+                y: parse_token(input, y, "a coordinate")?,
+            })
+        })
+        .collect::<Result<HashSet<Point>, _>>()?;
+
+    let folds = folds_str
+        .lines()
+        .map(|line| {
+            let instruction = line
+                .strip_prefix("fold along ")
+                .ok_or_else(|| ParseError::new(input, line, "fold along"))?;
+            match instruction.split_once('=') {
+                Some(("x", digit)) => Ok(Fold::X(parse_token(input, digit, "a coordinate")?)),
+                Some(("y", digit)) => Ok(Fold::Y(parse_token(input, digit, "a coordinate")?)),
+                _ => Err(ParseError::new(input, instruction, "x=<n> or y=<n>")),
+            }
+        })
+        .collect::<Result<Vec<Fold>, _>>()?;
+
+    Ok((points, folds))
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let (points, folds) = parse_input(input)?;
+
+    let points = folds.iter().fold(points, |pset, fold| {
+        pset.into_iter().map(|p| p.fold(fold)).collect()
+    });
+
+    Ok(Answer::Render(render_points(&points)))
+}
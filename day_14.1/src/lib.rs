@@ -0,0 +1,51 @@
+use aoc_common::{Answer, ParseError};
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let (polymer_string, transform_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "a blank line followed by the rules"))?;
+
+    let mut char_vec: Vec<char> = polymer_string.trim().chars().collect();
+
+    let transforms: HashMap<(char, char), char> = transform_str
+        .lines()
+        .map(|l| {
+            let (pair, insert) = l
+                .split_once(" -> ")
+                .filter(|(pair, insert)| pair.chars().count() == 2 && insert.chars().count() == 1)
+                .ok_or_else(|| ParseError::new(input, l, "a rule AB -> C"))?;
+            let mut pair = pair.chars();
+
+            Ok((
+                (pair.next().unwrap(), pair.next().unwrap()),
+                insert.chars().next().unwrap(),
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+
+    const N_STEPS: usize = 10;
+
+    for _ in 0..N_STEPS {
+        let insertions = char_vec
+            .iter()
+            .tuple_windows()
+            .map(|(c1, c2)| transforms.get(&(*c1, *c2)).unwrap());
+        char_vec = char_vec
+            .iter()
+            .interleave(insertions)
+            .copied()
+            .collect::<Vec<_>>();
+    }
+
+    let counts = char_vec.iter().counts();
+    let (min, max) = counts
+        .iter()
+        .minmax_by(|(_, v1), (_, v2)| v1.cmp(v2))
+        .into_option()
+        .unwrap();
+
+    Ok((max.1 - min.1).into())
+}
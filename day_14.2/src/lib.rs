@@ -0,0 +1,75 @@
+use aoc_common::{Answer, ParseError};
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let (polymer_string, transform_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "a blank line followed by the rules"))?;
+
+    let polymer_string = polymer_string.trim();
+    let mut char_couples: HashMap<(char, char), usize> =
+        polymer_string.chars().tuple_windows().counts();
+
+    let mut char_counts = polymer_string.chars().counts();
+
+    let transforms: HashMap<(char, char), char> = transform_str
+        .lines()
+        .map(|l| {
+            let (pair, insert) = l
+                .split_once(" -> ")
+                .filter(|(pair, insert)| pair.chars().count() == 2 && insert.chars().count() == 1)
+                .ok_or_else(|| ParseError::new(input, l, "a rule AB -> C"))?;
+            let mut pair = pair.chars();
+
+            Ok((
+                (pair.next().unwrap(), pair.next().unwrap()),
+                insert.chars().next().unwrap(),
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+
+    const N_STEPS: usize = 40;
+
+    for _ in 0..N_STEPS {
+        let insertions: Vec<((char, char), usize)> = char_couples
+            .iter()
+            .flat_map(|(couple, count)| {
+                let insert = transforms.get(couple).unwrap();
+
+                match char_counts.get_mut(insert) {
+                    Some(v) => *v += count,
+                    None => {
+                        char_counts.insert(*insert, *count);
+                    }
+                }
+
+                let new_couple_1 = (couple.0, *insert);
+                let new_couple_2 = (*insert, couple.1);
+
+                vec![(new_couple_1, *count), (new_couple_2, *count)]
+            })
+            .collect();
+
+        char_couples = HashMap::new();
+
+        insertions.iter().for_each(|(c, v)| {
+            let count = char_couples.get_mut(c);
+            match count {
+                Some(count) => *count += v,
+                None => {
+                    char_couples.insert(*c, *v);
+                }
+            }
+        });
+    }
+
+    let (min, max) = char_counts
+        .iter()
+        .minmax_by(|(_, v1), (_, v2)| v1.cmp(v2))
+        .into_option()
+        .unwrap();
+
+    Ok((max.1 - min.1).into())
+}
@@ -0,0 +1,19 @@
+use aoc_common::{parse_risk_grid, shortest_path, Answer, ParseError, Path, Search};
+
+pub fn find_path(input: &str, search: Search) -> Result<Path, ParseError> {
+    let grid = parse_risk_grid(input)?;
+    let goal = (grid.width() - 1, grid.height() - 1);
+
+    Ok(shortest_path(
+        (grid.width(), grid.height()),
+        |p| grid[p] as u32,
+        (0, 0),
+        goal,
+        search,
+    )
+    .expect("every cell of the map is reachable"))
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    Ok(find_path(input, Search::AStar)?.cost.into())
+}
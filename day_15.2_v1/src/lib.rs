@@ -0,0 +1,33 @@
+use aoc_common::{parse_risk_grid, shortest_path, Answer, Grid, ParseError, Path, Search};
+
+const ITERATIONS: usize = 5;
+
+pub fn find_path(input: &str, search: Search) -> Result<Path, ParseError> {
+    let base_grid = parse_risk_grid(input)?;
+    let (base_width, base_height) = (base_grid.width(), base_grid.height());
+
+    let grid = Grid::from_fn(
+        ITERATIONS * base_width,
+        ITERATIONS * base_height,
+        |(x, y)| {
+            let base_weight = base_grid[(x % base_width, y % base_height)] as u32;
+            let tile = (x / base_width + y / base_height) as u32;
+
+            ((base_weight + tile - 1) % 9) + 1
+        },
+    );
+    let goal = (grid.width() - 1, grid.height() - 1);
+
+    Ok(shortest_path(
+        (grid.width(), grid.height()),
+        |p| grid[p],
+        (0, 0),
+        goal,
+        search,
+    )
+    .expect("every cell of the map is reachable"))
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    Ok(find_path(input, Search::AStar)?.cost.into())
+}
@@ -0,0 +1,119 @@
+use ansi_term::Colour::Red;
+use aoc_common::{parse_risk_grid, shortest_path, Answer, Grid, ParseError, Path, Search};
+use std::{
+    collections::HashSet,
+    fmt,
+    io::{self, Write},
+};
+
+// The full map is the base map repeated `factor` times in both directions. Risks
+// increase by one on every tile to the right or below, wrapping from 9 back to 1.
+// Weights of the full map are computed on the fly, it is never built.
+pub struct TiledMap {
+    base_grid: Grid<u8>,
+    factor: usize,
+}
+
+impl TiledMap {
+    pub fn new(base_grid: Grid<u8>, factor: usize) -> Self {
+        assert!(factor > 0, "the tiling factor must be positive");
+        assert!(
+            base_grid.iter().all(|r| (1..=9).contains(r)),
+            "risk levels must be from 1 to 9"
+        );
+        TiledMap { base_grid, factor }
+    }
+
+    pub fn parse(input: &str, factor: usize) -> Result<Self, ParseError> {
+        Ok(TiledMap::new(parse_risk_grid(input)?, factor))
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (
+            self.factor * self.base_grid.width(),
+            self.factor * self.base_grid.height(),
+        )
+    }
+
+    pub fn weight(&self, (x, y): (usize, usize)) -> u32 {
+        let width = self.base_grid.width();
+        let height = self.base_grid.height();
+
+        let base_weight = self.base_grid[(x % width, y % height)] as u32;
+
+        let offset = x / width + y / height;
+
+        ((base_weight + offset as u32 - 1) % 9) + 1
+    }
+
+    pub fn find_path(&self, search: Search) -> Path {
+        let (width, height) = self.size();
+
+        shortest_path(
+            (width, height),
+            |p| self.weight(p),
+            (0, 0),
+            (width - 1, height - 1),
+            search,
+        )
+        .expect("every cell of the map is reachable")
+    }
+
+    pub fn view(&self, path: &Path) -> PathView<'_> {
+        PathView {
+            map: self,
+            on_path: path.positions.iter().copied().collect(),
+        }
+    }
+}
+
+// The risk map, with the cells of a path highlighted
+pub struct PathView<'a> {
+    map: &'a TiledMap,
+    on_path: HashSet<(usize, usize)>,
+}
+
+impl fmt::Display for PathView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = self.map.size();
+
+        (0..height).try_for_each(|y| {
+            (0..width).try_for_each(|x| {
+                let weight = self.map.weight((x, y));
+                if self.on_path.contains(&(x, y)) {
+                    write!(f, "{}", Red.bold().paint(weight.to_string()))
+                } else {
+                    write!(f, "{}", weight)
+                }
+            })?;
+            writeln!(f)
+        })
+    }
+}
+
+impl PathView<'_> {
+    // One pixel per cell: the path in red, other cells in shades of grey, darker
+    // for higher risks
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = self.map.size();
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .try_for_each(|p| {
+                let pixel = if self.on_path.contains(&p) {
+                    [255, 0, 0]
+                } else {
+                    let shade = (28 * (9 - self.map.weight(p)) + 3) as u8;
+                    [shade; 3]
+                };
+                out.write_all(&pixel)
+            })
+    }
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let map = TiledMap::parse(input, 5)?;
+
+    Ok(map.find_path(Search::AStar).cost.into())
+}
@@ -0,0 +1,82 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Write},
+    process,
+};
+
+use aoc_common::{Input, Search};
+use day_15_2_v2::TiledMap;
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: day_15_2_v2 <input> [tiling factor] [astar|dijkstra] [--show] [--ppm <file>]"
+    );
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 {
+        usage();
+    }
+    let input = Input::read(&args[1..2]).unwrap();
+
+    let mut show = false;
+    let mut ppm_file = None;
+    let mut positional = vec![];
+    let mut options = args[2..].iter();
+    while let Some(arg) = options.next() {
+        match arg.as_str() {
+            "--show" => show = true,
+            "--ppm" => ppm_file = Some(options.next().unwrap_or_else(|| usage())),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let factor = match positional.first().map(|s| s.parse()) {
+        None => 5,
+        Some(Ok(factor)) if factor > 0 => factor,
+        Some(_) => usage(),
+    };
+    let search = match positional.get(1) {
+        None | Some(&"astar") => Search::AStar,
+        Some(&"dijkstra") => Search::Dijkstra,
+        Some(_) => usage(),
+    };
+
+    let map = TiledMap::parse(&input.text, factor).unwrap_or_else(|e| {
+        eprintln!("{}", input.locate(e));
+        process::exit(1);
+    });
+    let path = map.find_path(search);
+    let (width, height) = map.size();
+
+    if show {
+        let stdout = io::stdout();
+        let mut out = BufWriter::new(stdout.lock());
+        if let Err(e) = write!(out, "{}", map.view(&path)).and_then(|_| out.flush()) {
+            eprintln!("Cannot write the map: {}", e);
+            process::exit(1);
+        }
+    }
+    if let Some(ppm_file) = ppm_file {
+        let written = File::create(ppm_file).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            map.view(&path).write_ppm(&mut out)?;
+            out.flush()
+        });
+        if let Err(e) = written {
+            eprintln!("Cannot write {}: {}", ppm_file, e);
+            process::exit(1);
+        }
+    }
+
+    println!("Map size: {}x{}", width, height);
+    println!("Path weight: {}", path.cost);
+    println!(
+        "Path length: {} cells ({} cells explored)",
+        path.positions.len(),
+        path.explored
+    );
+}
@@ -0,0 +1,201 @@
+use crate::{LengthType, Operator, Packet, PacketContent};
+use std::{error::Error, fmt};
+
+/// A packet that cannot be written in the BITS format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The value of a field does not fit in its number of bits.
+    Overflow { field: &'static str, value: u64 },
+    /// An operator with a number of subpackets it does not accept, which could not be
+    /// decoded.
+    BadArity {
+        operator: Operator,
+        subpackets: usize,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Overflow { field, value } => write!(f, "{} {} is too large", field, value),
+            EncodeError::BadArity {
+                operator,
+                subpackets,
+            } => write!(
+                f,
+                "the {} needs {}, not {}",
+                operator,
+                operator.arity(),
+                subpackets
+            ),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+fn literal_groups(value: u64) -> usize {
+    (64 - value.leading_zeros() as usize).div_ceil(4).max(1)
+}
+
+// Version, type ID, length type ID and length of the subpackets
+fn header_length(length_type: LengthType) -> usize {
+    match length_type {
+        LengthType::TotalLength => 22,
+        LengthType::Count => 18,
+    }
+}
+
+// Bits packed most significant first, `len` of them, the last byte padded with zeros
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    // Writes the `n_bits` low bits of `value`, at most 64, most significant bit first
+    fn write(&mut self, n_bits: usize, value: u64) {
+        let mut left = n_bits;
+        while left > 0 {
+            let offset = self.len % 8;
+            if offset == 0 {
+                self.bytes.push(0);
+            }
+            let taken = left.min(8 - offset);
+            let bits = (value >> (left - taken)) & ((1 << taken) - 1);
+
+            *self.bytes.last_mut().unwrap() |= (bits << (8 - offset - taken)) as u8;
+            self.len += taken;
+            left -= taken;
+        }
+    }
+
+    fn append(&mut self, other: BitWriter) {
+        for (i, &byte) in other.bytes.iter().enumerate() {
+            let n_bits = (other.len - 8 * i).min(8);
+            self.write(n_bits, (byte >> (8 - n_bits)) as u64);
+        }
+    }
+
+    fn write_integer(
+        &mut self,
+        n_bits: usize,
+        value: u64,
+        field: &'static str,
+    ) -> Result<(), EncodeError> {
+        if value >> n_bits != 0 {
+            return Err(EncodeError::Overflow { field, value });
+        }
+        self.write(n_bits, value);
+        Ok(())
+    }
+
+    // Groups of 4 bits, each prefixed by 1 except the last one
+    fn write_number(&mut self, value: u64) {
+        let groups = literal_groups(value);
+
+        for g in (0..groups).rev() {
+            self.write(1, (g > 0) as u64);
+            self.write(4, value >> (4 * g) & 15);
+        }
+    }
+
+    fn write_packet(&mut self, packet: &Packet) -> Result<(), EncodeError> {
+        self.write_integer(3, packet.version, "version")?;
+        self.write_integer(3, packet.type_id(), "type ID")?;
+
+        match &packet.content {
+            PacketContent::Number(n) => self.write_number(*n),
+            PacketContent::Operator {
+                operator,
+                subpackets,
+                ..
+            } if !operator.accepts(subpackets.len()) => {
+                return Err(EncodeError::BadArity {
+                    operator: *operator,
+                    subpackets: subpackets.len(),
+                })
+            }
+            PacketContent::Operator {
+                length_type: LengthType::Count,
+                subpackets,
+                ..
+            } => {
+                self.write(1, 1);
+                self.write_integer(11, subpackets.len() as u64, "number of subpackets")?;
+                for p in subpackets {
+                    self.write_packet(p)?;
+                }
+            }
+            PacketContent::Operator {
+                length_type: LengthType::TotalLength,
+                subpackets,
+                ..
+            } => {
+                let mut content = BitWriter::default();
+                for p in subpackets {
+                    content.write_packet(p)?;
+                }
+                self.write(1, 0);
+                self.write_integer(15, content.len as u64, "length of subpackets")?;
+                self.append(content);
+            }
+        }
+        Ok(())
+    }
+
+    // Padded with zeros to whole bytes, as the transmissions are
+    fn into_hex(self) -> String {
+        self.bytes.iter().map(|b| format!("{:02X}", b)).collect()
+    }
+}
+
+impl Packet {
+    /// Writes the packet as a transmission in hexadecimal, with the length type of each
+    /// operator.
+    pub fn encode(&self) -> Result<String, EncodeError> {
+        let mut writer = BitWriter::default();
+        writer.write_packet(self)?;
+        Ok(writer.into_hex())
+    }
+
+    /// Number of bits taken by the packet in a transmission, without the padding.
+    pub fn bit_length(&self) -> usize {
+        match &self.content {
+            PacketContent::Number(n) => 6 + 5 * literal_groups(*n),
+            PacketContent::Operator {
+                length_type,
+                subpackets,
+                ..
+            } => {
+                header_length(*length_type)
+                    + subpackets.iter().map(|p| p.bit_length()).sum::<usize>()
+            }
+        }
+    }
+
+    /// Position of a subpacket, given by the indices leading to it from this packet, in
+    /// bits from the start of this packet.
+    pub fn bit_offset(&self, path: &[usize]) -> usize {
+        match (&self.content, path) {
+            (_, []) => 0,
+            (
+                PacketContent::Operator {
+                    length_type,
+                    subpackets,
+                    ..
+                },
+                [i, rest @ ..],
+            ) => {
+                header_length(*length_type)
+                    + subpackets[..*i]
+                        .iter()
+                        .map(|p| p.bit_length())
+                        .sum::<usize>()
+                    + subpackets[*i].bit_offset(rest)
+            }
+            (PacketContent::Number(_), _) => panic!("literal values have no subpackets"),
+        }
+    }
+}
@@ -0,0 +1,398 @@
+use aoc_common::{Answer, ParseError, SolveError};
+use std::{error::Error, fmt};
+
+mod encode;
+mod reader;
+mod view;
+
+pub use encode::EncodeError;
+pub use reader::{BitReader, DecodeError};
+pub use view::{Expression, Tree};
+
+/// Number of operators a decoded packet can be nested in, so that the packets, which are
+/// walked recursively, cannot overflow the stack.
+pub const MAX_DEPTH: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub version: u64,
+    pub content: PacketContent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketContent {
+    Number(u64),
+    Operator {
+        operator: Operator,
+        length_type: LengthType,
+        subpackets: Vec<Packet>,
+    },
+}
+
+/// How an operator gives the extent of its subpackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// Length type 0: the total length of the subpackets, in bits.
+    TotalLength,
+    /// Length type 1: the number of subpackets.
+    Count,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl Operator {
+    /// The operator with the given type ID, 4 being the type of literal values.
+    pub fn from_type_id(type_id: u64) -> Option<Self> {
+        match type_id {
+            0 => Some(Operator::Sum),
+            1 => Some(Operator::Product),
+            2 => Some(Operator::Minimum),
+            3 => Some(Operator::Maximum),
+            5 => Some(Operator::GreaterThan),
+            6 => Some(Operator::LessThan),
+            7 => Some(Operator::EqualTo),
+            _ => None,
+        }
+    }
+
+    pub fn type_id(self) -> u64 {
+        match self {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Minimum => 2,
+            Operator::Maximum => 3,
+            Operator::GreaterThan => 5,
+            Operator::LessThan => 6,
+            Operator::EqualTo => 7,
+        }
+    }
+
+    /// Whether the operator can be applied to `n` values: comparisons take exactly 2,
+    /// the others at least 1.
+    pub fn accepts(self, n: usize) -> bool {
+        match self {
+            Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => n == 2,
+            _ => n >= 1,
+        }
+    }
+
+    // The numbers of subpackets accepted, as in "expected exactly 2 subpackets"
+    fn arity(self) -> &'static str {
+        if self.accepts(2) && !self.accepts(1) {
+            "exactly 2 subpackets"
+        } else {
+            "at least 1 subpacket"
+        }
+    }
+
+    // None when a sum or a product overflows, or for values the operator does not accept
+    fn apply(self, values: &[u64]) -> Option<u64> {
+        match (self, values) {
+            (Operator::Sum, _) => values.iter().try_fold(0u64, |acc, &v| acc.checked_add(v)),
+            (Operator::Product, _) => values.iter().try_fold(1u64, |acc, &v| acc.checked_mul(v)),
+            (Operator::Minimum, _) => values.iter().min().copied(),
+            (Operator::Maximum, _) => values.iter().max().copied(),
+            (Operator::GreaterThan, &[a, b]) => Some((a > b) as u64),
+            (Operator::LessThan, &[a, b]) => Some((a < b) as u64),
+            (Operator::EqualTo, &[a, b]) => Some((a == b) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Operator::Sum => "sum",
+            Operator::Product => "product",
+            Operator::Minimum => "minimum",
+            Operator::Maximum => "maximum",
+            Operator::GreaterThan => "greater than",
+            Operator::LessThan => "less than",
+            Operator::EqualTo => "equal to",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An operator packet that cannot be evaluated. `path` gives the indices of the
+/// subpackets leading from the outermost packet to the offending one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// A sum or a product that does not fit in 64 bits.
+    Overflow {
+        path: Vec<usize>,
+        operator: Operator,
+    },
+    /// An operator with a number of subpackets it does not accept.
+    BadArity {
+        path: Vec<usize>,
+        operator: Operator,
+        subpackets: usize,
+    },
+}
+
+impl EvalError {
+    pub fn path(&self) -> &[usize] {
+        match self {
+            EvalError::Overflow { path, .. } | EvalError::BadArity { path, .. } => path,
+        }
+    }
+
+    fn path_mut(&mut self) -> &mut Vec<usize> {
+        match self {
+            EvalError::Overflow { path, .. } | EvalError::BadArity { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            EvalError::Overflow { operator, .. } | EvalError::BadArity { operator, .. } => operator,
+        };
+        if self.path().is_empty() {
+            write!(f, "the {} of the outermost packet", operator)?;
+        } else {
+            let path: Vec<String> = self.path().iter().map(|i| i.to_string()).collect();
+            write!(f, "the {} of subpacket {}", operator, path.join("."))?;
+        }
+        match self {
+            EvalError::Overflow { .. } => write!(f, " does not fit in 64 bits"),
+            EvalError::BadArity { subpackets, .. } => {
+                write!(f, " needs {}, not {}", operator.arity(), subpackets)
+            }
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+impl Packet {
+    /// A literal value packet, of type 4.
+    pub fn literal(version: u64, value: u64) -> Self {
+        Packet {
+            version,
+            content: PacketContent::Number(value),
+        }
+    }
+
+    /// An operator packet, giving the number of its subpackets when it fits in 11 bits,
+    /// as it takes 4 bits less than their length.
+    pub fn operator(version: u64, operator: Operator, subpackets: Vec<Packet>) -> Self {
+        let length_type = if subpackets.len() < 1 << 11 {
+            LengthType::Count
+        } else {
+            LengthType::TotalLength
+        };
+
+        Packet {
+            version,
+            content: PacketContent::Operator {
+                operator,
+                length_type,
+                subpackets,
+            },
+        }
+    }
+
+    pub fn type_id(&self) -> u64 {
+        match &self.content {
+            PacketContent::Number(_) => 4,
+            PacketContent::Operator { operator, .. } => operator.type_id(),
+        }
+    }
+
+    fn get_sum_version(&self) -> u64 {
+        match &self.content {
+            PacketContent::Operator { subpackets, .. } => {
+                self.version + subpackets.iter().map(|p| p.get_sum_version()).sum::<u64>()
+            }
+            _ => self.version,
+        }
+    }
+
+    /// Value of the expression, or the first operator that cannot be evaluated: a sum or
+    /// product that does not fit in 64 bits, or an operator with a number of subpackets
+    /// it does not accept, which decoded packets never have.
+    pub fn evaluate(&self) -> Result<u64, EvalError> {
+        match &self.content {
+            PacketContent::Number(v) => Ok(*v),
+            PacketContent::Operator {
+                operator,
+                subpackets,
+                ..
+            } => {
+                if !operator.accepts(subpackets.len()) {
+                    return Err(EvalError::BadArity {
+                        path: vec![],
+                        operator: *operator,
+                        subpackets: subpackets.len(),
+                    });
+                }
+                let values = subpackets
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        p.evaluate().map_err(|mut e| {
+                            e.path_mut().insert(0, i);
+                            e
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                operator.apply(&values).ok_or(EvalError::Overflow {
+                    path: vec![],
+                    operator: *operator,
+                })
+            }
+        }
+    }
+}
+
+// Groups of 4 bits, each prefixed by 1 except the last one
+fn read_number(reader: &mut BitReader) -> Result<u64, DecodeError> {
+    let at = reader.position();
+    let mut acc: u64 = 0;
+    loop {
+        let group = reader.read(5, "literal value")?;
+
+        acc = acc
+            .checked_mul(16)
+            .ok_or(DecodeError::LiteralTooLarge { at })?
+            + (group & 15);
+        if group < 16 {
+            return Ok(acc);
+        }
+    }
+}
+
+// `depth` is the number of operators the packet is in
+fn parse_packet(reader: &mut BitReader, depth: usize) -> Result<Packet, DecodeError> {
+    let at = reader.position();
+    let version = reader.read(3, "version")?;
+    // 3-bit type IDs other than 4 are all operators
+    let operator = match Operator::from_type_id(reader.read(3, "type ID")?) {
+        None => return Ok(Packet::literal(version, read_number(reader)?)),
+        Some(_) if depth == MAX_DEPTH => return Err(DecodeError::TooDeep { at }),
+        Some(operator) => operator,
+    };
+
+    let mut subpackets = Vec::new();
+    let length_type = if reader.read(1, "length type ID")? == 0 {
+        let length_at = reader.position();
+        let length = reader.read(15, "length of subpackets")? as usize;
+        let mut sub_reader = reader.split(length, length_at)?;
+
+        // a subpacket running past the end is a bad length as well
+        while sub_reader.remaining() > 0 {
+            let p = parse_packet(&mut sub_reader, depth + 1).map_err(|e| match e {
+                DecodeError::Truncated { .. } => DecodeError::BadLength {
+                    at: length_at,
+                    length,
+                },
+                e => e,
+            })?;
+            subpackets.push(p);
+        }
+        LengthType::TotalLength
+    } else {
+        let n_packets = reader.read(11, "number of subpackets")?;
+
+        for _ in 0..n_packets {
+            subpackets.push(parse_packet(reader, depth + 1)?);
+        }
+        LengthType::Count
+    };
+
+    if !operator.accepts(subpackets.len()) {
+        return Err(DecodeError::BadArity {
+            at,
+            operator,
+            subpackets: subpackets.len(),
+        });
+    }
+
+    Ok(Packet {
+        version,
+        content: PacketContent::Operator {
+            operator,
+            length_type,
+            subpackets,
+        },
+    })
+}
+
+// The hexadecimal digit holding the bit at position `at`
+fn digit(hex: &str, at: usize) -> &str {
+    hex.get(at / 4..at / 4 + 1).unwrap_or(&hex[hex.len()..])
+}
+
+/// Decodes the packet at the start of the transmission, ignoring the padding after it.
+pub fn parse_transmission(input: &str) -> Result<Packet, ParseError> {
+    let hex = input.trim();
+    let mut reader = BitReader::new(hex).map_err(|i| {
+        let c = hex[i..].chars().next().unwrap();
+        ParseError::new(input, &hex[i..i + c.len_utf8()], "a hexadecimal digit")
+    })?;
+
+    // errors point at the hexadecimal digit holding the offending bit
+    parse_packet(&mut reader, 0).map_err(|e| match e {
+        DecodeError::Truncated { at, field } => {
+            ParseError::at_end(input, format!("the {} starting at bit {}", field, at))
+        }
+        DecodeError::BadLength { at, length } => ParseError::new(
+            input,
+            digit(hex, at),
+            format!("subpackets filling the {} bits given at bit {}", length, at),
+        ),
+        DecodeError::LiteralTooLarge { at } => ParseError::new(
+            input,
+            digit(hex, at),
+            format!("a literal value of at most 64 bits at bit {}", at),
+        ),
+        DecodeError::BadArity {
+            at,
+            operator,
+            subpackets,
+        } => ParseError::new(
+            input,
+            digit(hex, at),
+            format!(
+                "{} for the {} at bit {}, not {}",
+                operator.arity(),
+                operator,
+                at,
+                subpackets
+            ),
+        ),
+        DecodeError::TooDeep { at } => ParseError::new(
+            input,
+            digit(hex, at),
+            format!("operators nested at most {} deep at bit {}", MAX_DEPTH, at),
+        ),
+    })
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    Ok(parse_transmission(input)?.get_sum_version().into())
+}
+
+// The transmission is well-formed even when its value overflows
+pub fn solve_part2(input: &str) -> Result<Answer, SolveError> {
+    let packet = parse_transmission(input)?;
+
+    packet.evaluate().map(Answer::from).map_err(|e| {
+        let at = packet.bit_offset(e.path());
+        SolveError::Evaluation(format!("{}, at bit {}", e, at))
+    })
+}
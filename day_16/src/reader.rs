@@ -0,0 +1,121 @@
+use crate::Operator;
+use std::{error::Error, fmt};
+
+/// A transmission that does not hold a well-formed packet. Positions are in bits from
+/// the start of the transmission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The transmission ends in the middle of `field`, which starts at bit `at`.
+    Truncated { at: usize, field: &'static str },
+    /// The subpackets do not exactly fill the `length` bits given at bit `at`.
+    BadLength { at: usize, length: usize },
+    /// The literal value starting at bit `at` does not fit in 64 bits.
+    LiteralTooLarge { at: usize },
+    /// The operator of the packet starting at bit `at` does not accept its number of
+    /// subpackets.
+    BadArity {
+        at: usize,
+        operator: Operator,
+        subpackets: usize,
+    },
+    /// The operator starting at bit `at` is nested in `MAX_DEPTH` other operators.
+    TooDeep { at: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated { at, field } => {
+                write!(f, "bit {}: the transmission ends in the {}", at, field)
+            }
+            DecodeError::BadLength { at, length } => {
+                write!(f, "bit {}: the subpackets do not fill {} bits", at, length)
+            }
+            DecodeError::LiteralTooLarge { at } => {
+                write!(f, "bit {}: the literal value does not fit in 64 bits", at)
+            }
+            DecodeError::BadArity {
+                at,
+                operator,
+                subpackets,
+            } => write!(f, "bit {}: {} of {} subpackets", at, operator, subpackets),
+            DecodeError::TooDeep { at } => {
+                write!(f, "bit {}: operators nested too deep", at)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Reads the bits of a transmission straight from its hexadecimal digits.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    hex: &'a [u8],
+    position: usize,
+    end: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Reader over the bits of `hex`, or the byte index of its first character that is
+    /// not a hexadecimal digit.
+    pub fn new(hex: &'a str) -> Result<Self, usize> {
+        match hex.bytes().position(|b| !b.is_ascii_hexdigit()) {
+            Some(i) => Err(i),
+            None => Ok(BitReader {
+                hex: hex.as_bytes(),
+                position: 0,
+                end: 4 * hex.len(),
+            }),
+        }
+    }
+
+    /// Position of the next bit to read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.end - self.position
+    }
+
+    /// Reads the next `n_bits` bits, at most 64, as an integer, most significant bit first.
+    pub fn read(&mut self, n_bits: usize, field: &'static str) -> Result<u64, DecodeError> {
+        assert!(n_bits <= 64, "cannot read {} bits at once", n_bits);
+        if n_bits > self.remaining() {
+            return Err(DecodeError::Truncated {
+                at: self.position,
+                field,
+            });
+        }
+
+        let mut value = 0;
+        let mut left = n_bits;
+        while left > 0 {
+            let digit = (self.hex[self.position / 4] as char).to_digit(16).unwrap() as u64;
+            let offset = self.position % 4;
+            let taken = left.min(4 - offset);
+            let bits = (digit >> (4 - offset - taken)) & ((1 << taken) - 1);
+
+            value = (value << taken) | bits;
+            self.position += taken;
+            left -= taken;
+        }
+        Ok(value)
+    }
+
+    /// A reader over the next `length` bits, which this one skips. `at` is the position of
+    /// the length field, for errors.
+    pub fn split(&mut self, length: usize, at: usize) -> Result<BitReader<'a>, DecodeError> {
+        if length > self.remaining() {
+            return Err(DecodeError::BadLength { at, length });
+        }
+
+        let sub_reader = BitReader {
+            end: self.position + length,
+            ..self.clone()
+        };
+        self.position += length;
+        Ok(sub_reader)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{LengthType, Operator, Packet, PacketContent};
+use std::fmt;
+
+/// The packet tree, one packet per line, with the version of each packet and the
+/// length type of the operators.
+pub struct Tree<'a>(&'a Packet);
+
+fn write_tree(
+    f: &mut fmt::Formatter<'_>,
+    packet: &Packet,
+    indent: &str,
+    branch: &str,
+    child_indent: &str,
+) -> fmt::Result {
+    write!(f, "{}{}", indent, branch)?;
+    match &packet.content {
+        PacketContent::Number(n) => writeln!(f, "literal {} (v{})", n, packet.version),
+        PacketContent::Operator {
+            operator: name,
+            length_type,
+            subpackets,
+        } => {
+            match length_type {
+                LengthType::TotalLength => {
+                    let length: usize = subpackets.iter().map(|p| p.bit_length()).sum();
+                    writeln!(
+                        f,
+                        "{} (v{}, length type 0: {} bits)",
+                        name, packet.version, length
+                    )?
+                }
+                LengthType::Count => writeln!(
+                    f,
+                    "{} (v{}, length type 1: {} subpacket{})",
+                    name,
+                    packet.version,
+                    subpackets.len(),
+                    if subpackets.len() == 1 { "" } else { "s" }
+                )?,
+            }
+
+            let indent = format!("{}{}", indent, child_indent);
+            for (i, p) in subpackets.iter().enumerate() {
+                if i + 1 == subpackets.len() {
+                    write_tree(f, p, &indent, "└── ", "    ")?;
+                } else {
+                    write_tree(f, p, &indent, "├── ", "│   ")?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for Tree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.0, "", "", "")
+    }
+}
+
+/// The formula computed by the packet, such as `max(3, (1 + 2) * 7)`.
+pub struct Expression<'a>(&'a Packet);
+
+// Binding strength of the operators written infix, to know where parentheses are needed
+fn precedence(operator: Operator, operands: usize) -> u8 {
+    match operator {
+        Operator::GreaterThan | Operator::LessThan | Operator::EqualTo if operands == 2 => 1,
+        Operator::Sum if operands > 1 => 2,
+        Operator::Product if operands > 1 => 3,
+        _ => 4,
+    }
+}
+
+fn write_expression(f: &mut fmt::Formatter<'_>, packet: &Packet, parent: u8) -> fmt::Result {
+    let (operator, subpackets) = match &packet.content {
+        PacketContent::Number(n) => return write!(f, "{}", n),
+        PacketContent::Operator {
+            operator,
+            subpackets,
+            ..
+        } => (*operator, subpackets),
+    };
+    let precedence = precedence(operator, subpackets.len());
+    let write_list = |f: &mut fmt::Formatter<'_>, separator: &str, min: u8| {
+        for (i, p) in subpackets.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", separator)?;
+            }
+            write_expression(f, p, min)?;
+        }
+        Ok(())
+    };
+
+    if precedence < parent {
+        write!(f, "(")?;
+    }
+    match (operator, precedence) {
+        (Operator::Sum, 2) => write_list(f, " + ", 2)?,
+        (Operator::Product, 3) => write_list(f, " * ", 3)?,
+        (Operator::GreaterThan, 1) => write_list(f, " > ", 2)?,
+        (Operator::LessThan, 1) => write_list(f, " < ", 2)?,
+        (Operator::EqualTo, 1) => write_list(f, " == ", 2)?,
+        // a sum or product of a single value is that value
+        (Operator::Sum, _) | (Operator::Product, _) if subpackets.len() == 1 => {
+            write_expression(f, &subpackets[0], parent)?
+        }
+        (operator, _) => {
+            let name = match operator {
+                Operator::Minimum => "min".to_string(),
+                Operator::Maximum => "max".to_string(),
+                operator => operator.to_string().replace(' ', "_"),
+            };
+            write!(f, "{}(", name)?;
+            write_list(f, ", ", 0)?;
+            write!(f, ")")?;
+        }
+    }
+    if precedence < parent {
+        write!(f, ")")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Expression<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_expression(f, self.0, 0)
+    }
+}
+
+impl Packet {
+    pub fn tree(&self) -> Tree<'_> {
+        Tree(self)
+    }
+
+    pub fn expression(&self) -> Expression<'_> {
+        Expression(self)
+    }
+}
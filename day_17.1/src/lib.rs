@@ -0,0 +1,61 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+type TargetArea = ((i32, i32), (i32, i32));
+
+fn vertical_velocity_ok(v: u32, min: u32, max: u32) -> bool {
+    let mut x = 0;
+    let mut v = v;
+    while x <= max {
+        if x >= min {
+            return true;
+        }
+        x += v;
+        v += 1;
+    }
+    false
+}
+
+fn parse_range(input: &str, s: &str, axis: &str) -> Result<(i32, i32), ParseError> {
+    let r = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| ParseError::new(input, s, format!("{}=<min>..<max>", axis)))?;
+    let (min_s, max_s) = r
+        .split_once("..")
+        .ok_or_else(|| ParseError::new(input, r, "<min>..<max>"))?;
+
+    Ok((
+        parse_token(input, min_s, "a number")?,
+        parse_token(input, max_s, "a number")?,
+    ))
+}
+
+fn parse_target_area(input: &str) -> Result<TargetArea, ParseError> {
+    // target area: x=20..30, y=-10..-5
+    let line = input.trim();
+    let area = line
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::new(input, line, "target area:"))?;
+    let (x_str, y_str) = area
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(input, area, "x=<min>..<max>, y=<min>..<max>"))?;
+
+    Ok((
+        parse_range(input, x_str, "x")?,
+        parse_range(input, y_str, "y")?,
+    ))
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let (_, (y_min, y_max)) = parse_target_area(input)?;
+
+    let rev_bb_min = -y_max as u32;
+    let rev_bb_max = -y_min as u32;
+
+    let v_max = (0..rev_bb_max)
+        .filter(|v| vertical_velocity_ok(*v, rev_bb_min, rev_bb_max))
+        .max()
+        .unwrap();
+
+    Ok(((v_max + 1) * v_max / 2).into())
+}
@@ -1,27 +1,16 @@
-fn vertical_velocity_ok(v: u32, min: u32, max: u32) -> bool {
-    let mut x = 0;
-    let mut v = v;
-    while x <= max {
-        if x >= min {
-            return true;
-        }
-        x += v;
-        v += 1;
-    }
-    false
-}
-
-fn main() {
-    let (y_min, y_max) = (-198, -148);
-    // let (y_min, y_max) = (-10, -5);
+use std::{env, process};
 
-    let rev_bb_min = -y_max as u32;
-    let rev_bb_max = -y_min as u32;
+use aoc_common::Input;
 
-    let v_max = (0..rev_bb_max)
-        .filter(|v| vertical_velocity_ok(*v, rev_bb_min, rev_bb_max))
-        .max()
-        .unwrap();
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let input = Input::read(&args[1..2]).unwrap();
 
-    println!("y_max {:?}", (v_max + 1) * v_max / 2);
+    match day_17_1::solve_part1(&input.text) {
+        Ok(answer) => println!("y_max {}", answer),
+        Err(e) => {
+            eprintln!("{}", input.locate(e));
+            process::exit(1);
+        }
+    }
 }
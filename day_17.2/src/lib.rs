@@ -0,0 +1,109 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+type TargetArea = ((i32, i32), (i32, i32));
+
+type Step = ((i32, i32), (i32, i32));
+
+fn is_in_trench(x_range: (i32, i32), y_range: (i32, i32), (x, y): (i32, i32)) -> bool {
+    ((x_range.0 <= x) && (x <= x_range.1)) && ((y_range.0 <= y) && (y <= y_range.1))
+}
+
+fn gen_trajectory(
+    (vx, vy): (i32, i32),
+    x_trench_range: (i32, i32),
+    y_trench_range: (i32, i32),
+) -> Vec<Step> {
+    let (mut vx, mut vy) = (vx, vy);
+    let (mut x, mut y) = (0, 0);
+    (0..)
+        .map(|_iter| {
+            let res = ((x, y), (vx, vy));
+            x += vx;
+            y += vy;
+            if vx > 0 {
+                vx -= 1
+            };
+            vy -= 1;
+
+            res
+        })
+        .take_while(|((_x, y), _)| *y >= y_trench_range.0.min(y_trench_range.1))
+        .take_while(|((x, _y), _)| *x <= x_trench_range.0.max(x_trench_range.1))
+        .collect()
+}
+
+fn intersects(trajectory: &[Step], x_trench_range: (i32, i32), y_trench_range: (i32, i32)) -> bool {
+    is_in_trench(x_trench_range, y_trench_range, trajectory.last().unwrap().0)
+}
+
+fn vertical_velocity_ok(v: i32, min: i32, max: i32) -> bool {
+    let mut x = 0;
+    let mut v = v;
+    while x <= max {
+        if x >= min {
+            return true;
+        }
+        x += v;
+        v += 1;
+    }
+    false
+}
+
+fn parse_range(input: &str, s: &str, axis: &str) -> Result<(i32, i32), ParseError> {
+    let r = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| ParseError::new(input, s, format!("{}=<min>..<max>", axis)))?;
+    let (min_s, max_s) = r
+        .split_once("..")
+        .ok_or_else(|| ParseError::new(input, r, "<min>..<max>"))?;
+
+    Ok((
+        parse_token(input, min_s, "a number")?,
+        parse_token(input, max_s, "a number")?,
+    ))
+}
+
+fn parse_target_area(input: &str) -> Result<TargetArea, ParseError> {
+    // target area: x=20..30, y=-10..-5
+    let line = input.trim();
+    let area = line
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::new(input, line, "target area:"))?;
+    let (x_str, y_str) = area
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(input, area, "x=<min>..<max>, y=<min>..<max>"))?;
+
+    Ok((
+        parse_range(input, x_str, "x")?,
+        parse_range(input, y_str, "y")?,
+    ))
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let ((x_min, x_max), (y_min, y_max)) = parse_target_area(input)?;
+
+    let rev_bb_min = -y_max;
+    let rev_bb_max = -y_min;
+
+    let vx_max = (0..=x_max)
+        .filter(|v| vertical_velocity_ok(*v, x_min, x_max))
+        .max()
+        .unwrap();
+    let vy_max = (0..=rev_bb_max)
+        .filter(|v| vertical_velocity_ok(*v, rev_bb_min, rev_bb_max))
+        .max()
+        .unwrap();
+
+    Ok((1..=vx_max)
+        .map(|vx| {
+            (-vy_max..=vy_max)
+                .filter(move |vy| {
+                    let traj = gen_trajectory((vx, *vy), (x_min, x_max), (y_min, y_max));
+                    intersects(&traj, (x_min, x_max), (y_min, y_max))
+                })
+                .count()
+        })
+        .sum::<usize>()
+        .into())
+}
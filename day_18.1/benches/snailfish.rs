@@ -0,0 +1,68 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_18_1::{parse_numbers, FlatNumber, Node};
+
+fn boxed_sum(numbers: &[Node]) -> u32 {
+    let mut sum = numbers[0].clone();
+    for n in &numbers[1..] {
+        sum = sum + n.clone();
+        sum.reduce();
+    }
+    sum.magnitude()
+}
+
+fn flat_sum(numbers: &[FlatNumber]) -> u32 {
+    let mut sum = numbers[0].clone();
+    for n in &numbers[1..] {
+        sum = sum + n.clone();
+        sum.reduce();
+    }
+    sum.magnitude()
+}
+
+fn boxed_pairs(numbers: &[Node]) -> u32 {
+    let mut largest = 0;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                let mut sum = a.clone() + b.clone();
+                sum.reduce();
+                largest = largest.max(sum.magnitude());
+            }
+        }
+    }
+    largest
+}
+
+fn flat_pairs(numbers: &[FlatNumber]) -> u32 {
+    let mut largest = 0;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                let mut sum = a.clone() + b.clone();
+                sum.reduce();
+                largest = largest.max(sum.magnitude());
+            }
+        }
+    }
+    largest
+}
+
+fn add_numbers(c: &mut Criterion) {
+    let numbers = parse_numbers(include_str!("../input")).unwrap();
+    let flat: Vec<FlatNumber> = numbers.iter().map(FlatNumber::from).collect();
+    assert_eq!(boxed_sum(&numbers), flat_sum(&flat));
+
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("boxed", |b| b.iter(|| boxed_sum(black_box(&numbers))));
+    group.bench_function("flat", |b| b.iter(|| flat_sum(black_box(&flat))));
+    group.finish();
+
+    let mut group = c.benchmark_group("ordered pairs");
+    group.sample_size(10);
+    group.bench_function("boxed", |b| b.iter(|| boxed_pairs(black_box(&numbers))));
+    group.bench_function("flat", |b| b.iter(|| flat_pairs(black_box(&flat))));
+    group.finish();
+}
+
+criterion_group!(benches, add_numbers);
+criterion_main!(benches);
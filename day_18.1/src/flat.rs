@@ -0,0 +1,118 @@
+use crate::Node;
+use std::{fmt, ops::Add};
+
+/// A snailfish number stored as the list of its regular numbers from left to right, each
+/// with the number of pairs it is nested in. Adding and cloning are copies of a single
+/// vector, and reducing never walks a tree.
+///
+/// The first two adjacent regular numbers with the same depth are always a pair: if the
+/// left one were a right child, its sibling would hold such two numbers further left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatNumber {
+    regulars: Vec<(u32, u32)>,
+}
+
+impl FlatNumber {
+    fn explode(&mut self) -> bool {
+        let i = match self
+            .regulars
+            .windows(2)
+            .position(|w| w[0].1 >= 5 && w[0].1 == w[1].1)
+        {
+            Some(i) => i,
+            None => return false,
+        };
+        let (left, depth) = self.regulars[i];
+        let right = self.regulars[i + 1].0;
+
+        if i > 0 {
+            self.regulars[i - 1].0 += left;
+        }
+        if let Some(next) = self.regulars.get_mut(i + 2) {
+            next.0 += right;
+        }
+        self.regulars[i] = (0, depth - 1);
+        self.regulars.remove(i + 1);
+        true
+    }
+
+    fn split(&mut self) -> bool {
+        let i = match self.regulars.iter().position(|&(value, _)| value >= 10) {
+            Some(i) => i,
+            None => return false,
+        };
+        let (value, depth) = self.regulars[i];
+
+        self.regulars[i] = (value / 2, depth + 1);
+        self.regulars.insert(i + 1, (value - value / 2, depth + 1));
+        true
+    }
+
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    // Rebuilds the pairs from left to right: the stack holds left children waiting for
+    // their sibling, so two entries of the same depth on top are a pair.
+    fn fold<T>(&self, regular: impl Fn(u32) -> T, pair: impl Fn(T, T) -> T) -> T {
+        let mut stack: Vec<(T, u32)> = Vec::new();
+
+        for &(value, depth) in &self.regulars {
+            let mut top = (regular(value), depth);
+            while let Some(&(_, d)) = stack.last() {
+                if d != top.1 {
+                    break;
+                }
+                let (left, _) = stack.pop().unwrap();
+                top = (pair(left, top.0), d - 1);
+            }
+            stack.push(top);
+        }
+
+        stack.pop().unwrap().0
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        self.fold(|value| value, |left, right| 3 * left + 2 * right)
+    }
+}
+
+fn flatten(node: &Node, depth: u32, regulars: &mut Vec<(u32, u32)>) {
+    match node {
+        Node::Number(value) => regulars.push((*value, depth)),
+        Node::Pair(left, right) => {
+            flatten(left, depth + 1, regulars);
+            flatten(right, depth + 1, regulars);
+        }
+    }
+}
+
+impl From<&Node> for FlatNumber {
+    fn from(node: &Node) -> Self {
+        let mut regulars = Vec::new();
+        flatten(node, 0, &mut regulars);
+        FlatNumber { regulars }
+    }
+}
+
+impl From<&FlatNumber> for Node {
+    fn from(number: &FlatNumber) -> Self {
+        number.fold(Node::Number, |left, right| left + right)
+    }
+}
+
+impl Add for FlatNumber {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.regulars.extend(other.regulars);
+        self.regulars.iter_mut().for_each(|(_, depth)| *depth += 1);
+        self
+    }
+}
+
+impl fmt::Display for FlatNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Node::from(self))
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::{parse_lines, Answer, ParseError};
+use core::fmt;
+use std::ops::Add;
+
+mod flat;
+mod parse;
+mod trace;
+
+pub use flat::FlatNumber;
+pub use trace::{Action, Side, Step, Trace};
+
+/// Number of pairs a parsed regular number can be nested in, so that the numbers, which
+/// are parsed and walked recursively, cannot overflow the stack.
+pub const MAX_DEPTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Number(u32),
+    Pair(Box<Node>, Box<Node>),
+}
+
+impl Node {
+    // Splits the leftmost regular number of 10 or more, `path` leading to `self`
+    fn split(&mut self, path: &mut Vec<Side>) -> Option<Action> {
+        match self {
+            Node::Number(d) if *d >= 10 => {
+                let value = *d;
+                *self = Node::Pair(
+                    Box::new(Node::Number(value / 2)),
+                    Box::new(Node::Number(value - value / 2)),
+                );
+                Some(Action::Split {
+                    path: path.clone(),
+                    value,
+                })
+            }
+            Node::Pair(left, right) => {
+                path.push(Side::Left);
+                if let Some(action) = left.split(path) {
+                    return Some(action);
+                }
+                path.pop();
+                path.push(Side::Right);
+                let action = right.split(path);
+                path.pop();
+                action
+            }
+            _ => None,
+        }
+    }
+
+    fn add_leftmost_number(&mut self, num: u32) {
+        match self {
+            Node::Number(d) => *d += num,
+            Node::Pair(left, _right) => left.add_leftmost_number(num),
+        }
+    }
+    fn add_rightmost_number(&mut self, num: u32) {
+        match self {
+            Node::Number(d) => *d += num,
+            Node::Pair(_left, right) => right.add_rightmost_number(num),
+        }
+    }
+
+    // Explodes the leftmost pair nested inside four pairs. Also returns the values of the
+    // pair that still have to be added to the regular numbers left and right of `self`.
+    fn explode(
+        &mut self,
+        depth: usize,
+        path: &mut Vec<Side>,
+    ) -> Option<(Action, Option<u32>, Option<u32>)> {
+        let (left, right) = match self {
+            Node::Number(_) => return None,
+            Node::Pair(left, right) => (left, right),
+        };
+
+        if let (Node::Number(lv), Node::Number(rv)) = (left.as_ref(), right.as_ref()) {
+            if depth < 4 {
+                return None;
+            }
+            let pair = (*lv, *rv);
+            *self = Node::Number(0);
+            let action = Action::Explode {
+                path: path.clone(),
+                pair,
+            };
+            return Some((action, Some(pair.0), Some(pair.1)));
+        }
+
+        path.push(Side::Left);
+        if let Some((action, spill_left, spill_right)) = left.explode(depth + 1, path) {
+            if let Some(d) = spill_right {
+                right.add_leftmost_number(d);
+            }
+            return Some((action, spill_left, None));
+        }
+        path.pop();
+
+        path.push(Side::Right);
+        let explosion = right.explode(depth + 1, path);
+        path.pop();
+        let (action, spill_left, spill_right) = explosion?;
+        if let Some(d) = spill_left {
+            left.add_rightmost_number(d);
+        }
+        Some((action, None, spill_right))
+    }
+
+    /// Applies the first action reducing the number, if any: explode the leftmost pair
+    /// nested inside four pairs, or else split the leftmost regular number of 10 or more.
+    pub fn reduce_step(&mut self) -> Option<Action> {
+        match self.explode(0, &mut Vec::new()) {
+            Some((action, _, _)) => Some(action),
+            None => self.split(&mut Vec::new()),
+        }
+    }
+
+    pub fn reduce(&mut self) {
+        while self.reduce_step().is_some() {}
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        match self {
+            Node::Number(d) => *d,
+            Node::Pair(l, r) => 3 * l.magnitude() + 2 * r.magnitude(),
+        }
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Number(d) => write!(f, "{}", d),
+            Node::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl Add for Node {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Node::Pair(Box::new(self), Box::new(other))
+    }
+}
+
+/// One snailfish number per line.
+pub fn parse_numbers(input: &str) -> Result<Vec<Node>, ParseError> {
+    parse_lines(input)
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let numbers = parse_numbers(input)?;
+
+    let sum = numbers
+        .into_iter()
+        .reduce(|a, b| {
+            let mut s = a + b;
+            s.reduce();
+            s
+        })
+        .unwrap();
+
+    Ok(sum.magnitude().into())
+}
@@ -0,0 +1,82 @@
+use crate::{Node, MAX_DEPTH};
+use aoc_common::{parse_token, ParseError};
+use std::str::FromStr;
+
+// Recursive descent over a snailfish number, `rest` being what is left of `input`
+struct Parser<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    // The next character, as a slice of the input for errors
+    fn peek(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let c = self.rest.chars().next()?;
+        Some(&self.rest[..c.len_utf8()])
+    }
+
+    fn error(&mut self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(token) => ParseError::new(self.input, token, expected),
+            None => ParseError::at_end(self.input, expected),
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(t) if t == token => {
+                self.rest = &self.rest[1..];
+                Ok(())
+            }
+            _ => Err(self.error(token)),
+        }
+    }
+
+    // `depth` is the number of pairs the node is in
+    fn node(&mut self, depth: usize) -> Result<Node, ParseError> {
+        match self.peek() {
+            Some(t @ "[") if depth == MAX_DEPTH => Err(ParseError::new(
+                self.input,
+                t,
+                format!("pairs nested at most {} deep", MAX_DEPTH),
+            )),
+            Some("[") => {
+                self.rest = &self.rest[1..];
+                let left = self.node(depth + 1)?;
+                self.expect(",")?;
+                let right = self.node(depth + 1)?;
+                self.expect("]")?;
+                Ok(Node::Pair(Box::new(left), Box::new(right)))
+            }
+            Some(t) if t.as_bytes()[0].is_ascii_digit() => {
+                let end = self
+                    .rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(self.rest.len());
+                let (digits, rest) = self.rest.split_at(end);
+                self.rest = rest;
+                parse_token(self.input, digits, "a regular number").map(Node::Number)
+            }
+            _ => Err(self.error("a regular number or [")),
+        }
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, rest: s };
+        let node = parser.node(0)?;
+
+        match parser.peek() {
+            Some(_) => Err(parser.error("the end of the snailfish number")),
+            None => Ok(node),
+        }
+    }
+}
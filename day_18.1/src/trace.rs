@@ -0,0 +1,94 @@
+use crate::Node;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// One step of the reduction of a snailfish number. `path` leads from the outermost pair
+/// to the pair that exploded or to the regular number that split.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Explode { path: Vec<Side>, pair: (u32, u32) },
+    Split { path: Vec<Side>, value: u32 },
+}
+
+impl Action {
+    pub fn path(&self) -> &[Side] {
+        match self {
+            Action::Explode { path, .. } | Action::Split { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Explode { pair, .. } => write!(f, "explode [{},{}] at ", pair.0, pair.1)?,
+            Action::Split { value, .. } => write!(f, "split {} at ", value)?,
+        }
+
+        if self.path().is_empty() {
+            return write!(f, "the root");
+        }
+        for side in self.path() {
+            match side {
+                Side::Left => write!(f, "L")?,
+                Side::Right => write!(f, "R")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub action: Action,
+    /// The number once the action is applied.
+    pub number: Node,
+}
+
+/// The reduction of the sum of two snailfish numbers, action by action. It is shown as in
+/// the puzzle statement, one number per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub sum: Node,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn add(a: Node, b: Node) -> Self {
+        let sum = a + b;
+        let mut number = sum.clone();
+        let mut steps = Vec::new();
+
+        while let Some(action) = number.reduce_step() {
+            steps.push(Step {
+                action,
+                number: number.clone(),
+            });
+        }
+
+        Trace { sum, steps }
+    }
+
+    /// The reduced sum.
+    pub fn result(&self) -> &Node {
+        self.steps.last().map_or(&self.sum, |s| &s.number)
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "after addition: {}", self.sum)?;
+        for step in &self.steps {
+            match step.action {
+                Action::Explode { .. } => writeln!(f, "after explode:  {}", step.number)?,
+                Action::Split { .. } => writeln!(f, "after split:    {}", step.number)?,
+            }
+        }
+        Ok(())
+    }
+}
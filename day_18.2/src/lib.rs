@@ -0,0 +1,52 @@
+use aoc_common::{Answer, ParseError};
+use day_18_1::{parse_numbers, FlatNumber, Node};
+use rayon::prelude::*;
+use std::cmp::Reverse;
+
+/// The sum of two different numbers of the homework with the largest magnitude. `left`
+/// and `right` are the indices of the numbers, in the order they are added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargestSum {
+    pub left: usize,
+    pub right: usize,
+    pub sum: Node,
+    pub magnitude: u32,
+}
+
+/// Tries every ordered pair of different numbers, in parallel. Among pairs with the same
+/// magnitude, the first one in the order of the homework is kept. `None` if there are
+/// fewer than two numbers.
+pub fn largest_sum(numbers: &[Node]) -> Option<LargestSum> {
+    let flat: Vec<FlatNumber> = numbers.iter().map(FlatNumber::from).collect();
+    let add = |i: usize, j: usize| {
+        let mut sum = flat[i].clone() + flat[j].clone();
+        sum.reduce();
+        sum
+    };
+
+    let (magnitude, Reverse(left), Reverse(right)) = (0..flat.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            (0..flat.len())
+                .filter(move |&j| j != i)
+                .map(move |j| (i, j))
+        })
+        .map(|(i, j)| (add(i, j).magnitude(), Reverse(i), Reverse(j)))
+        .max()?;
+
+    Some(LargestSum {
+        left,
+        right,
+        sum: Node::from(&add(left, right)),
+        magnitude,
+    })
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let numbers = parse_numbers(input)?;
+
+    match largest_sum(&numbers) {
+        Some(largest) => Ok(largest.magnitude.into()),
+        None => Err(ParseError::at_end(input, "at least two snailfish numbers")),
+    }
+}
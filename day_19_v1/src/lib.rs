@@ -0,0 +1,295 @@
+use aoc_common::{align, parse_token, Answer, ParseError, Point, SolveError, Transform};
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display},
+};
+
+// Number of beacons two scanners must both detect for their regions to overlap
+const OVERLAP: usize = 12;
+
+#[derive(Debug)]
+struct Probe {
+    x: i64,
+    y: i64,
+    z: i64,
+    distances: Vec<u64>,
+    // share_count: usize,
+    // intersects: Vec<usize>,
+}
+#[derive(Debug)]
+struct Scanner(Vec<Probe>);
+
+impl Probe {
+    fn diff_coords(&self, other: &Probe) -> (i64, i64, i64) {
+        let diff_x = self.x - other.x;
+        let diff_y = self.y - other.y;
+        let diff_z = self.z - other.z;
+
+        (diff_x, diff_y, diff_z)
+    }
+
+    fn distance_squared(&self, other: &Probe) -> u64 {
+        let (diff_x, diff_y, diff_z) = self.diff_coords(other);
+
+        (diff_x * diff_x + diff_y * diff_y + diff_z * diff_z) as u64
+    }
+
+    fn point(&self) -> Point {
+        (self.x, self.y, self.z)
+    }
+
+    fn transform(&mut self, transform: &Transform) {
+        let (x, y, z) = transform.apply(self.point());
+        self.x = x;
+        self.y = y;
+        self.z = z;
+    }
+}
+
+impl Display for Probe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+fn compute_distances(probes: &mut [Probe]) {
+    let size = probes.len();
+    (0..size).for_each(|i| {
+        probes[i].distances = vec![0; size];
+
+        (0..size).for_each(|j| {
+            probes[i].distances[j] = probes[i].distance_squared(&probes[j]);
+        })
+    })
+}
+
+fn find_common_distances(list1: &[u64], list2: &[u64]) -> Vec<(usize, usize)> {
+    let mut list1: Vec<_> = list1.iter().enumerate().collect();
+    let mut list2: Vec<_> = list2.iter().enumerate().collect();
+
+    list1.sort_by_key(|(_, d1)| *d1);
+    list2.sort_by_key(|(_, d1)| *d1);
+
+    let mut result = Vec::<(usize, usize)>::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < list1.len() && j < list2.len() {
+        let (pos_1, d_1) = list1[i];
+        let (pos_2, d_2) = list2[j];
+
+        match d_1.cmp(d_2) {
+            std::cmp::Ordering::Equal => {
+                // if *d_1 != 0 {
+                result.push((pos_1, pos_2));
+                // }
+                i += 1;
+                j += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                j += 1;
+            }
+            std::cmp::Ordering::Less => {
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
+// A probe seen by both scanners is at the same distances from the `OVERLAP - 1` other
+// shared probes, and at distance 0 from itself
+fn match_distances(scanner_1: &Scanner, scanner_2: &Scanner) -> Vec<(usize, usize)> {
+    let mut common_probes = Vec::new();
+    scanner_1.0.iter().enumerate().for_each(|(i, p_1)| {
+        scanner_2.0.iter().enumerate().for_each(|(j, p_2)| {
+            let common = find_common_distances(&p_1.distances, &p_2.distances);
+
+            if common.len() >= OVERLAP {
+                common_probes.push((i, j));
+            }
+        });
+    });
+    common_probes
+}
+
+fn manhattan_distance((x0, y0, z0): Point, (x1, y1, z1): Point) -> i64 {
+    (x0 - x1).abs() + (y0 - y1).abs() + (z0 - z1).abs()
+}
+
+// The number in the header of a scanner: 0 for the first one, then above the number
+// `previous` of the scanner before it
+fn parse_header(input: &str, line: &str, previous: Option<usize>) -> Result<usize, ParseError> {
+    let number = line
+        .strip_prefix("--- scanner ")
+        .and_then(|l| l.strip_suffix(" ---"))
+        .and_then(|n| n.parse().ok());
+
+    match (number, previous) {
+        (Some(0), None) => Ok(0),
+        (Some(n), Some(p)) if n > p => Ok(n),
+        (_, None) => Err(ParseError::new(input, line, "--- scanner 0 ---")),
+        (_, Some(p)) => Err(ParseError::new(
+            input,
+            line,
+            format!("--- scanner <n> --- with n above {}", p),
+        )),
+    }
+}
+
+// Every scanner starts with its header and ends at a blank line or at the next header.
+// Returns the numbers in the headers along with the scanners.
+fn parse_scanners(input: &str) -> Result<(Vec<usize>, Vec<Scanner>), ParseError> {
+    let mut numbers = Vec::new();
+    let mut scanners = Vec::<Scanner>::new();
+    // probes of the scanner being read, None until its header
+    let mut probes: Option<Vec<Probe>> = None;
+
+    for l in input.lines() {
+        if l.is_empty() {
+            // end of scanner
+            scanners.extend(probes.take().map(Scanner));
+            continue;
+        }
+        if l.starts_with("---") {
+            scanners.extend(probes.take().map(Scanner));
+        }
+        let probes = match &mut probes {
+            Some(probes) => probes,
+            None => {
+                numbers.push(parse_header(input, l, numbers.last().copied())?);
+                probes = Some(Vec::new());
+                continue;
+            }
+        };
+
+        let coords = l
+            .split(',')
+            .map(|c| parse_token(input, c, "a coordinate"))
+            .collect::<Result<Vec<i64>, _>>()?;
+        if coords.len() != 3 {
+            return Err(ParseError::new(input, l, "3 coordinates x,y,z"));
+        }
+
+        probes.push(Probe {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+            distances: Vec::new(),
+        });
+    }
+
+    // don't forget the last scanner
+    scanners.extend(probes.map(Scanner));
+    Ok((numbers, scanners))
+}
+
+impl Scanner {
+    fn points(&self) -> Vec<Point> {
+        self.0.iter().map(|p| p.point()).collect()
+    }
+}
+
+// align every scanner on the axis and origin of scanner 0
+// returns the transform of every scanner to the coordinates of scanner 0, or the scanners
+// sharing fewer than `OVERLAP` beacons with scanner 0 and all the scanners aligned with it
+fn align_scanners(scanners: &mut [Scanner]) -> Result<Vec<Transform>, Vec<usize>> {
+    if scanners.is_empty() {
+        return Ok(vec![]);
+    }
+    scanners
+        .iter_mut()
+        .for_each(|s| compute_distances(&mut s.0));
+
+    let mut transforms = vec![None; scanners.len()];
+    transforms[0] = Some(Transform::IDENTITY);
+    let mut base_indices = vec![0];
+    let mut unprocessed_indices: Vec<_> = (1..scanners.len()).collect();
+
+    while let Some(base) = base_indices.pop() {
+        // the base scanner is already in the coordinates of scanner 0
+        let base_points = scanners[base].points();
+
+        unprocessed_indices.retain(|&index| {
+            let common_probes = match_distances(&scanners[base], &scanners[index]);
+            match align(
+                &base_points,
+                &scanners[index].points(),
+                &common_probes,
+                OVERLAP,
+            ) {
+                Some(transform) => {
+                    scanners[index]
+                        .0
+                        .iter_mut()
+                        .for_each(|p| p.transform(&transform));
+                    transforms[index] = Some(transform);
+                    base_indices.push(index);
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+
+    if !unprocessed_indices.is_empty() {
+        unprocessed_indices.sort_unstable();
+        return Err(unprocessed_indices);
+    }
+
+    Ok(transforms.into_iter().map(Option::unwrap).collect())
+}
+
+// "scanner 3", "scanners 3 and 4", "scanners 2, 3 and 4"
+fn list_scanners(scanners: &[usize]) -> String {
+    match scanners {
+        [] => "no scanner".into(),
+        [s] => format!("scanner {}", s),
+        [first @ .., last] => {
+            let first: Vec<String> = first.iter().map(|s| s.to_string()).collect();
+            format!("scanners {} and {}", first.join(", "), last)
+        }
+    }
+}
+
+// The input is well-formed even when the scanners cannot all be aligned
+fn aligned_scanners(input: &str) -> Result<(Vec<Scanner>, Vec<Transform>), SolveError> {
+    let (numbers, mut scanners) = parse_scanners(input)?;
+
+    match align_scanners(&mut scanners) {
+        Ok(transforms) => Ok((scanners, transforms)),
+        Err(unconnected) => Err(SolveError::Evaluation(format!(
+            "every scanner must share at least {} beacons with scanner 0 or a scanner aligned \
+             with it, not {}",
+            OVERLAP,
+            list_scanners(&unconnected.iter().map(|&i| numbers[i]).collect::<Vec<_>>())
+        ))),
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, SolveError> {
+    let (scanners, _) = aligned_scanners(input)?;
+
+    let set: HashSet<Point> = scanners.iter().flat_map(|s| s.points()).collect();
+
+    // count the  probes
+    Ok(set.len().into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, SolveError> {
+    let (_, transforms) = aligned_scanners(input)?;
+    let scanner_locs: Vec<Point> = transforms.iter().map(|t| t.offset).collect();
+
+    Ok(scanner_locs
+        .iter()
+        .filter_map(|loc1| {
+            scanner_locs
+                .iter()
+                .map(|loc2| manhattan_distance(*loc1, *loc2))
+                .max()
+        })
+        .max()
+        .unwrap_or(0)
+        .into())
+}
@@ -0,0 +1,392 @@
+use aoc_common::{parse_token, Answer, ParseError, SolveError};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+mod map;
+
+pub use aoc_common::{align, Point, Rotation, Transform};
+pub use map::{BeaconMap, Csv, Json, Table};
+
+/// Number of beacons two scanners must both detect for their regions to overlap. It is
+/// at least 3, as fewer beacons do not give the rotation between the scanners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap(usize);
+
+impl Overlap {
+    pub fn new(beacons: usize) -> Option<Self> {
+        if beacons >= 3 {
+            Some(Overlap(beacons))
+        } else {
+            None
+        }
+    }
+
+    pub fn beacons(self) -> usize {
+        self.0
+    }
+}
+
+/// The overlap of the puzzle.
+pub const OVERLAP: Overlap = Overlap(12);
+
+/// Why the scanners cannot be located.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanError {
+    Parse(ParseError),
+    /// The scanners, by the number in their header, sharing fewer than `overlap` beacons
+    /// with scanner 0 and all the scanners aligned with it.
+    Unconnected {
+        scanners: Vec<usize>,
+        overlap: Overlap,
+    },
+}
+
+impl From<ParseError> for ScanError {
+    fn from(e: ParseError) -> Self {
+        ScanError::Parse(e)
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanError::Parse(e) => write!(f, "{}", e),
+            ScanError::Unconnected { scanners, overlap } => write!(
+                f,
+                "every scanner must share at least {} beacons with scanner 0 or a scanner \
+                 aligned with it, not {}",
+                overlap.beacons(),
+                list_scanners(scanners)
+            ),
+        }
+    }
+}
+
+impl Error for ScanError {}
+
+// The input is well-formed even when the scanners cannot all be aligned
+impl From<ScanError> for SolveError {
+    fn from(e: ScanError) -> Self {
+        match e {
+            ScanError::Parse(e) => SolveError::Parse(e),
+            e => SolveError::Evaluation(e.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Probe {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+#[derive(Debug)]
+struct Scanner(Vec<Probe>);
+
+impl Probe {
+    fn diff_coords(&self, other: &Probe) -> (i64, i64, i64) {
+        let diff_x = self.x - other.x;
+        let diff_y = self.y - other.y;
+        let diff_z = self.z - other.z;
+
+        (diff_x, diff_y, diff_z)
+    }
+
+    fn distance_squared(&self, other: &Probe) -> u64 {
+        let (diff_x, diff_y, diff_z) = self.diff_coords(other);
+
+        (diff_x * diff_x + diff_y * diff_y + diff_z * diff_z) as u64
+    }
+
+    fn point(&self) -> Point {
+        (self.x, self.y, self.z)
+    }
+
+    fn transform(&mut self, transform: &Transform) {
+        let (x, y, z) = transform.apply(self.point());
+        self.x = x;
+        self.y = y;
+        self.z = z;
+    }
+}
+
+impl Display for Probe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// Squared distances between the probes of a scanner, with the pairs of probes `(i, j)`,
+// `i < j`, found at each of them. They do not depend on the orientation of the scanner.
+type Fingerprint = HashMap<u64, Vec<(usize, usize)>>;
+
+fn fingerprint(scanner: &Scanner) -> Fingerprint {
+    let mut fingerprint = Fingerprint::new();
+    for (i, p_1) in scanner.0.iter().enumerate() {
+        for (j, p_2) in scanner.0.iter().enumerate().skip(i + 1) {
+            fingerprint
+                .entry(p_1.distance_squared(p_2))
+                .or_default()
+                .push((i, j));
+        }
+    }
+    fingerprint
+}
+
+// Pairs of probes (i, j) of two scanners that are likely the same beacon. When the
+// scanners share `overlap` beacons, they share the `overlap * (overlap - 1) / 2` distances
+// between them, and each of these beacons is at one of these distances from `overlap - 1`
+// others in both scanners.
+fn match_distances(
+    fingerprint_1: &Fingerprint,
+    fingerprint_2: &Fingerprint,
+    overlap: Overlap,
+) -> Vec<(usize, usize)> {
+    let overlap = overlap.beacons();
+    let shared: Vec<_> = fingerprint_1
+        .iter()
+        .filter_map(|(d, pairs_1)| fingerprint_2.get(d).map(|pairs_2| (pairs_1, pairs_2)))
+        .collect();
+    let shared_count: usize = shared
+        .iter()
+        .map(|(pairs_1, pairs_2)| pairs_1.len().min(pairs_2.len()))
+        .sum();
+    if shared_count < overlap * (overlap - 1) / 2 {
+        return vec![];
+    }
+
+    let mut votes = HashMap::<(usize, usize), usize>::new();
+    for (pairs_1, pairs_2) in shared {
+        for &(a, b) in pairs_1 {
+            for &(c, d) in pairs_2 {
+                for common in [(a, c), (a, d), (b, c), (b, d)] {
+                    *votes.entry(common).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    let mut common_probes: Vec<_> = votes
+        .into_iter()
+        .filter(|&(_, count)| count + 1 >= overlap)
+        .map(|(common, _)| common)
+        .collect();
+    common_probes.sort_unstable();
+    common_probes
+}
+
+fn manhattan_distance((x0, y0, z0): Point, (x1, y1, z1): Point) -> i64 {
+    (x0 - x1).abs() + (y0 - y1).abs() + (z0 - z1).abs()
+}
+
+// The number in the header of a scanner: 0 for the first one, then above the number
+// `previous` of the scanner before it
+fn parse_header(input: &str, line: &str, previous: Option<usize>) -> Result<usize, ParseError> {
+    let number = line
+        .strip_prefix("--- scanner ")
+        .and_then(|l| l.strip_suffix(" ---"))
+        .and_then(|n| n.parse().ok());
+
+    match (number, previous) {
+        (Some(0), None) => Ok(0),
+        (Some(n), Some(p)) if n > p => Ok(n),
+        (_, None) => Err(ParseError::new(input, line, "--- scanner 0 ---")),
+        (_, Some(p)) => Err(ParseError::new(
+            input,
+            line,
+            format!("--- scanner <n> --- with n above {}", p),
+        )),
+    }
+}
+
+// Every scanner starts with its header and ends at a blank line or at the next header.
+// Returns the numbers in the headers along with the scanners.
+fn parse_scanners(input: &str) -> Result<(Vec<usize>, Vec<Scanner>), ParseError> {
+    let mut numbers = Vec::new();
+    let mut scanners = Vec::<Scanner>::new();
+    // probes of the scanner being read, None until its header
+    let mut probes: Option<Vec<Probe>> = None;
+
+    for l in input.lines() {
+        if l.is_empty() {
+            // end of scanner
+            scanners.extend(probes.take().map(Scanner));
+            continue;
+        }
+        if l.starts_with("---") {
+            scanners.extend(probes.take().map(Scanner));
+        }
+        let probes = match &mut probes {
+            Some(probes) => probes,
+            None => {
+                numbers.push(parse_header(input, l, numbers.last().copied())?);
+                probes = Some(Vec::new());
+                continue;
+            }
+        };
+
+        let coords = l
+            .split(',')
+            .map(|c| parse_token(input, c, "a coordinate"))
+            .collect::<Result<Vec<i64>, _>>()?;
+        if coords.len() != 3 {
+            return Err(ParseError::new(input, l, "3 coordinates x,y,z"));
+        }
+
+        probes.push(Probe {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+        });
+    }
+
+    // don't forget the last scanner
+    scanners.extend(probes.map(Scanner));
+    Ok((numbers, scanners))
+}
+
+impl Scanner {
+    fn points(&self) -> Vec<Point> {
+        self.0.iter().map(|p| p.point()).collect()
+    }
+}
+
+// "scanner 3", "scanners 3 and 4", "scanners 2, 3 and 4"
+fn list_scanners(scanners: &[usize]) -> String {
+    match scanners {
+        [] => "no scanner".into(),
+        [s] => format!("scanner {}", s),
+        [first @ .., last] => {
+            let first: Vec<String> = first.iter().map(|s| s.to_string()).collect();
+            format!("scanners {} and {}", first.join(", "), last)
+        }
+    }
+}
+
+type Alignment = (Vec<Transform>, Vec<(usize, usize)>);
+
+// align every scanner on the axis and origin of scanner 0
+// returns the transform of every scanner to the coordinates of scanner 0, and the pairs
+// (base, scanner) of scanners aligned from one another, or the scanners sharing fewer than
+// `overlap` beacons with scanner 0 and all the scanners aligned with it
+fn align_scanners(scanners: &mut [Scanner], overlap: Overlap) -> Result<Alignment, Vec<usize>> {
+    if scanners.is_empty() {
+        return Ok((vec![], vec![]));
+    }
+    let fingerprints: Vec<Fingerprint> = scanners.iter().map(fingerprint).collect();
+
+    let mut transforms = vec![None; scanners.len()];
+    transforms[0] = Some(Transform::IDENTITY);
+    let mut links = Vec::new();
+    let mut base_indices = vec![0];
+    let mut unprocessed_indices: Vec<_> = (1..scanners.len()).collect();
+
+    while let Some(base) = base_indices.pop() {
+        // the base scanner is already in the coordinates of scanner 0
+        let base_points = scanners[base].points();
+
+        unprocessed_indices.retain(|&index| {
+            let common_probes = match_distances(&fingerprints[base], &fingerprints[index], overlap);
+            if common_probes.is_empty() {
+                return true;
+            }
+            match align(
+                &base_points,
+                &scanners[index].points(),
+                &common_probes,
+                overlap.beacons(),
+            ) {
+                Some(transform) => {
+                    scanners[index]
+                        .0
+                        .iter_mut()
+                        .for_each(|p| p.transform(&transform));
+                    transforms[index] = Some(transform);
+                    links.push((base, index));
+                    base_indices.push(index);
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+
+    if !unprocessed_indices.is_empty() {
+        unprocessed_indices.sort_unstable();
+        return Err(unprocessed_indices);
+    }
+
+    let transforms = transforms.into_iter().map(Option::unwrap).collect();
+    Ok((transforms, links))
+}
+
+fn aligned_scanners(input: &str, overlap: Overlap) -> Result<(Vec<Scanner>, Alignment), ScanError> {
+    let (numbers, mut scanners) = parse_scanners(input)?;
+
+    match align_scanners(&mut scanners, overlap) {
+        Ok(alignment) => Ok((scanners, alignment)),
+        Err(unconnected) => Err(ScanError::Unconnected {
+            scanners: unconnected.iter().map(|&i| numbers[i]).collect(),
+            overlap,
+        }),
+    }
+}
+
+/// The transforms from the coordinates of each scanner to the ones of scanner 0, for
+/// scanners overlapping when they share at least `overlap` beacons. The offset of a
+/// transform is the position of the scanner.
+pub fn locate_scanners(input: &str, overlap: Overlap) -> Result<Vec<Transform>, ScanError> {
+    aligned_scanners(input, overlap).map(|(_, (transforms, _))| transforms)
+}
+
+/// Number of distinct beacons detected by the scanners.
+pub fn count_beacons(input: &str, overlap: Overlap) -> Result<usize, ScanError> {
+    let (scanners, _) = aligned_scanners(input, overlap)?;
+    let set: HashSet<Point> = scanners.iter().flat_map(|s| s.points()).collect();
+
+    Ok(set.len())
+}
+
+/// Largest Manhattan distance between two scanners.
+pub fn largest_distance(input: &str, overlap: Overlap) -> Result<i64, ScanError> {
+    let scanner_locs: Vec<Point> = locate_scanners(input, overlap)?
+        .iter()
+        .map(|t| t.offset)
+        .collect();
+
+    Ok(scanner_locs
+        .iter()
+        .flat_map(|loc1| {
+            scanner_locs
+                .iter()
+                .map(move |loc2| manhattan_distance(*loc1, *loc2))
+        })
+        .max()
+        .unwrap_or(0))
+}
+
+/// The positions and rotations of the scanners, how they were aligned, and the beacons
+/// they detect, all in the coordinates of scanner 0.
+pub fn map_beacons(input: &str, overlap: Overlap) -> Result<BeaconMap, ScanError> {
+    let (scanners, (transforms, links)) = aligned_scanners(input, overlap)?;
+    let set: HashSet<Point> = scanners.iter().flat_map(|s| s.points()).collect();
+    let mut beacons: Vec<Point> = set.into_iter().collect();
+    beacons.sort_unstable();
+
+    Ok(BeaconMap {
+        scanners: transforms,
+        links,
+        beacons,
+    })
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, SolveError> {
+    Ok(count_beacons(input, OVERLAP)?.into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, SolveError> {
+    Ok(largest_distance(input, OVERLAP)?.into())
+}
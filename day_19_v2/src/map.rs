@@ -0,0 +1,164 @@
+use crate::{Point, Transform};
+use std::fmt;
+
+/// The reconstruction of the region: each scanner has a transform to the coordinates of
+/// scanner 0, `links` are the pairs `(base, scanner)` of scanners aligned from one another,
+/// and `beacons` are sorted and without duplicates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeaconMap {
+    pub scanners: Vec<Transform>,
+    pub links: Vec<(usize, usize)>,
+    pub beacons: Vec<Point>,
+}
+
+impl BeaconMap {
+    /// The scanner `scanner` was aligned from, `None` for scanner 0.
+    pub fn aligned_from(&self, scanner: usize) -> Option<usize> {
+        self.links
+            .iter()
+            .find(|&&(_, s)| s == scanner)
+            .map(|&(base, _)| base)
+    }
+
+    pub fn table(&self) -> Table<'_> {
+        Table(self)
+    }
+
+    pub fn json(&self) -> Json<'_> {
+        Json(self)
+    }
+
+    pub fn csv(&self) -> Csv<'_> {
+        Csv(self)
+    }
+}
+
+fn point((x, y, z): Point) -> String {
+    format!("({}, {}, {})", x, y, z)
+}
+
+/// One line per scanner, followed by the beacons.
+pub struct Table<'a>(&'a BeaconMap);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.0;
+        let positions: Vec<String> = map.scanners.iter().map(|t| point(t.offset)).collect();
+        let rotations: Vec<String> = map
+            .scanners
+            .iter()
+            .map(|t| t.rotation.to_string())
+            .collect();
+        let position_width = positions.iter().map(|p| p.len()).max().unwrap_or(0);
+        let rotation_width = rotations.iter().map(|r| r.len()).max().unwrap_or(0);
+
+        writeln!(
+            f,
+            "Scanner  {:<pw$}  {:<rw$}  Aligned from",
+            "Position",
+            "Rotation",
+            pw = position_width,
+            rw = rotation_width
+        )?;
+        for (i, (position, rotation)) in positions.iter().zip(&rotations).enumerate() {
+            let base = match map.aligned_from(i) {
+                Some(base) => base.to_string(),
+                None => "-".into(),
+            };
+            writeln!(
+                f,
+                "{:>7}  {:<pw$}  {:<rw$}  {:>12}",
+                i,
+                position,
+                rotation,
+                base,
+                pw = position_width,
+                rw = rotation_width
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{} beacons:", map.beacons.len())?;
+        for &beacon in &map.beacons {
+            writeln!(f, "  {}", point(beacon))?;
+        }
+        Ok(())
+    }
+}
+
+/// An object with the `scanners` (index, position, rotation matrix and the scanner they
+/// were aligned from), the `links` between scanners and the `beacons`.
+pub struct Json<'a>(&'a BeaconMap);
+
+impl fmt::Display for Json<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.0;
+        let array = |p: Point| format!("[{}, {}, {}]", p.0, p.1, p.2);
+
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"scanners\": [")?;
+        for (i, t) in map.scanners.iter().enumerate() {
+            let rows: Vec<String> = t
+                .rotation
+                .0
+                .iter()
+                .map(|r| array((r[0], r[1], r[2])))
+                .collect();
+            let base = match map.aligned_from(i) {
+                Some(base) => base.to_string(),
+                None => "null".into(),
+            };
+            writeln!(
+                f,
+                "    {{\"index\": {}, \"position\": {}, \"rotation\": [{}], \
+                 \"aligned_from\": {}}}{}",
+                i,
+                array(t.offset),
+                rows.join(", "),
+                base,
+                if i + 1 < map.scanners.len() { "," } else { "" }
+            )?;
+        }
+        writeln!(f, "  ],")?;
+
+        let links: Vec<String> = map
+            .links
+            .iter()
+            .map(|(base, s)| format!("[{}, {}]", base, s))
+            .collect();
+        writeln!(f, "  \"links\": [{}],", links.join(", "))?;
+
+        writeln!(f, "  \"beacons\": [")?;
+        for (i, &beacon) in map.beacons.iter().enumerate() {
+            let separator = if i + 1 < map.beacons.len() { "," } else { "" };
+            writeln!(f, "    {}{}", array(beacon), separator)?;
+        }
+        writeln!(f, "  ]")?;
+        writeln!(f, "}}")
+    }
+}
+
+/// One record per scanner then per beacon: `kind,index,x,y,z,rotation,aligned_from`, the
+/// last two fields being empty for beacons.
+pub struct Csv<'a>(&'a BeaconMap);
+
+impl fmt::Display for Csv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.0;
+
+        writeln!(f, "kind,index,x,y,z,rotation,aligned_from")?;
+        for (i, t) in map.scanners.iter().enumerate() {
+            let (x, y, z) = t.offset;
+            let base = map.aligned_from(i).map_or(String::new(), |b| b.to_string());
+            writeln!(
+                f,
+                "scanner,{},{},{},{},\"{}\",{}",
+                i, x, y, z, t.rotation, base
+            )?;
+        }
+        for (i, &(x, y, z)) in map.beacons.iter().enumerate() {
+            writeln!(f, "beacon,{},{},{},{},,", i, x, y, z)?;
+        }
+        Ok(())
+    }
+}
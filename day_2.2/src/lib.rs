@@ -0,0 +1,56 @@
+use aoc_common::{parse_lines, parse_token, Answer, ParseError};
+use std::str::FromStr;
+
+enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, v) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, s, "a command and a value"))?;
+        let v = parse_token(s, v, "a number")?;
+
+        match command {
+            "forward" => Ok(Command::Forward(v)),
+            "down" => Ok(Command::Down(v)),
+            "up" => Ok(Command::Up(v)),
+            _ => Err(ParseError::new(s, command, "forward, down or up")),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Location {
+    pos: u32,
+    depth: u32,
+    aim: u32,
+}
+
+impl Location {
+    fn apply(mut self, command: Command) -> Self {
+        // let mut copy = self.clone();
+        match command {
+            Command::Forward(x) => {
+                self.pos += x;
+                self.depth += self.aim * x;
+            }
+            Command::Down(x) => self.aim += x,
+            Command::Up(x) => self.aim = self.aim.saturating_sub(x),
+        }
+        self
+    }
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let loc = parse_lines::<Command>(input)?
+        .into_iter()
+        .fold(Location::default(), |loc, command| loc.apply(command));
+
+    Ok((loc.pos * loc.depth).into())
+}
@@ -0,0 +1,73 @@
+use aoc_common::{Answer, Grid, ParseError};
+
+fn add_margins(grid: &Grid<bool>, margin: usize) -> Grid<bool> {
+    let margin = margin as isize;
+
+    Grid::from_fn(
+        grid.width() + 2 * margin as usize,
+        grid.height() + 2 * margin as usize,
+        |(x, y)| grid.get_or((x as isize - margin, y as isize - margin), false),
+    )
+}
+
+// The 3x3 square around (x, y), read as a binary number
+fn get_surrounding_value(grid: &Grid<bool>, (x, y): (usize, usize), oob_value: bool) -> usize {
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| (x as isize + dx, y as isize + dy)))
+        .fold(0, |v, p| 2 * v + grid.get_or(p, oob_value) as usize)
+}
+
+fn apply_transformation(grid: &Grid<bool>, transform: &[bool], even: bool) -> Grid<bool> {
+    let oob_value = if transform[0] { !even } else { false };
+
+    Grid::from_fn(grid.width(), grid.height(), |p| {
+        transform[get_surrounding_value(grid, p, oob_value)]
+    })
+}
+
+fn count_light(grid: &Grid<bool>) -> usize {
+    grid.iter().filter(|v| **v).count()
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    }
+}
+
+pub fn enhance(input: &str, iterations: usize) -> Result<usize, ParseError> {
+    let (transform_str, map_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "a blank line followed by the image"))?;
+
+    let transform_str = transform_str.trim();
+    let transform = transform_str
+        .char_indices()
+        .map(|(i, c)| {
+            parse_pixel(c).ok_or_else(|| {
+                ParseError::new(input, &transform_str[i..i + c.len_utf8()], ". or #")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if transform.len() != 512 {
+        return Err(ParseError::new(input, transform_str, "512 pixels"));
+    }
+
+    let grid = Grid::parse(map_str, parse_pixel, ". or #").map_err(|e| e.within(input, map_str))?;
+    let mut grid = add_margins(&grid, iterations);
+
+    for i in 0..iterations {
+        grid = apply_transformation(&grid, &transform, i % 2 == 0);
+    }
+    Ok(count_light(&grid))
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    enhance(input, 2).map(Answer::from)
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    enhance(input, 50).map(Answer::from)
+}
@@ -0,0 +1,51 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+fn parse_positions(input: &str) -> Result<[u16; 2], ParseError> {
+    let mut positions = input.lines().map(|l| {
+        let (_, p) = l
+            .rsplit_once(": ")
+            .ok_or_else(|| ParseError::new(input, l, "Player <n> starting position: <p>"))?;
+        match parse_token::<u16>(input, p, "a position")? {
+            p @ 1..=10 => Ok(p - 1),
+            _ => Err(ParseError::new(input, p, "a position between 1 and 10")),
+        }
+    });
+    let mut next_position = || {
+        positions
+            .next()
+            .unwrap_or_else(|| Err(ParseError::at_end(input, "a second player")))
+    };
+
+    Ok([next_position()?, next_position()?])
+}
+
+// positions are 0-indexed
+pub fn play(positions: [u16; 2]) -> usize {
+    let mut positions = positions;
+    let mut scores = [0u32, 0u32];
+    let mut dice_value = 0u16;
+    let mut n_rolls = 0usize;
+
+    let mut player = 0;
+    while scores[0] < 1000 && scores[1] < 1000 {
+        let offset1 = (dice_value + 1) % 100;
+        dice_value = (dice_value + 1) % 100;
+        let offset2 = (dice_value + 1) % 100;
+        dice_value = (dice_value + 1) % 100;
+        let offset3 = (dice_value + 1) % 100;
+        dice_value = (dice_value + 1) % 100;
+
+        n_rolls += 3;
+
+        positions[player] = (positions[player] + offset1 + offset2 + offset3) % 10;
+        scores[player] += (positions[player] + 1) as u32;
+
+        player = if player == 0 { 1 } else { 0 };
+    }
+
+    n_rolls * (scores[0].min(scores[1]) as usize)
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    Ok(play(parse_positions(input)?).into())
+}
@@ -0,0 +1,258 @@
+use aoc_common::{parse_token, Answer, ParseError, SolveError};
+use std::{collections::BTreeMap, error::Error, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct ScoreState {
+    pub positions: [u16; 2],
+    pub scores: [u16; 2],
+    pub player: u8,
+}
+
+/// Largest target score. Games are explored one turn deeper at a time, and every turn
+/// scores at least 1 point, so the target bounds the depth of the recursion.
+pub const MAX_TARGET: u16 = 1000;
+
+/// The parameters of a game of Dirac Dice. The default ones are the puzzle's: a board of
+/// 10 spaces, 21 points to win, and 3 rolls of a 3-sided die per turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub board_size: u16,
+    pub target: u16,
+    pub die_faces: u16,
+    pub rolls: u16,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            board_size: 10,
+            target: 21,
+            die_faces: 3,
+            rolls: 3,
+        }
+    }
+}
+
+/// A game that cannot be played, or whose universes cannot be counted in 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    /// The board has no spaces, or the die has no faces.
+    Empty,
+    /// The target score is above `MAX_TARGET`.
+    TargetTooLarge { target: u16 },
+    /// A position is off the board, a score has reached the target, or the player to
+    /// move is neither 0 nor 1.
+    InvalidState,
+    /// A number of universes does not fit in 64 bits.
+    Overflow,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::Empty => write!(f, "the board has no spaces or the die has no faces"),
+            GameError::TargetTooLarge { target } => {
+                write!(f, "a target of {} is above {}", target, MAX_TARGET)
+            }
+            GameError::InvalidState => write!(f, "the game cannot start from this state"),
+            GameError::Overflow => write!(f, "the number of universes does not fit in 64 bits"),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+impl Rules {
+    /// Whether a game can be played with these rules.
+    pub fn check(&self) -> Result<(), GameError> {
+        if self.board_size == 0 || self.die_faces == 0 {
+            Err(GameError::Empty)
+        } else if self.target > MAX_TARGET {
+            Err(GameError::TargetTooLarge {
+                target: self.target,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_state(&self, state: &ScoreState) -> Result<(), GameError> {
+        self.check()?;
+        if state.player > 1
+            || state.positions.iter().any(|&p| p >= self.board_size)
+            || state.scores.iter().any(|&s| s >= self.target)
+        {
+            Err(GameError::InvalidState)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Number of universes in which the rolls of a turn add up to each sum, indexed by the
+    /// sum: `[0, 0, 0, 1, 3, 6, 7, 6, 3, 1]` for 3 rolls of a 3-sided die.
+    pub fn roll_distribution(&self) -> Result<Vec<u64>, GameError> {
+        let faces = self.die_faces as usize;
+        // every count is at most the number of universes of a turn
+        (self.die_faces as u64)
+            .checked_pow(self.rolls as u32)
+            .ok_or(GameError::Overflow)?;
+
+        let mut distribution = vec![1];
+        for _ in 0..self.rolls {
+            // next[sum] adds up the counts of the `faces` sums below it
+            let mut next = vec![0; distribution.len() + faces];
+            let mut window = 0;
+            for (sum, count) in next.iter_mut().enumerate().skip(1) {
+                window += distribution.get(sum - 1).unwrap_or(&0);
+                if sum > faces {
+                    window -= distribution.get(sum - 1 - faces).unwrap_or(&0);
+                }
+                *count = window;
+            }
+            distribution = next;
+        }
+        Ok(distribution)
+    }
+
+    // The state after the current player moves by `offset`, or `None` if they win
+    fn advance(&self, state: &ScoreState, offset: usize) -> Option<ScoreState> {
+        let p = state.player as usize;
+        let board_size = self.board_size as usize;
+        let new_pos = (state.positions[p] as usize + offset % board_size) % board_size;
+        let new_score = state.scores[p] as usize + new_pos + 1;
+
+        if new_score >= self.target as usize {
+            None
+        } else {
+            let mut new_state = state.clone();
+            new_state.scores[p] = new_score as u16;
+            new_state.positions[p] = new_pos as u16;
+            new_state.player = if p == 0 { 1 } else { 0 };
+            Some(new_state)
+        }
+    }
+}
+
+// Adds `count` times the wins of `sub_wins` to `n_wins`
+fn add_wins(n_wins: &mut [u64; 2], count: u64, sub_wins: [u64; 2]) -> Result<(), GameError> {
+    for (n, sub) in n_wins.iter_mut().zip(sub_wins) {
+        *n = count
+            .checked_mul(sub)
+            .and_then(|c| n.checked_add(c))
+            .ok_or(GameError::Overflow)?;
+    }
+    Ok(())
+}
+
+// The wins when the current player wins in a single universe
+fn win(state: &ScoreState) -> [u64; 2] {
+    let mut wins = [0, 0];
+    wins[state.player as usize] = 1;
+    wins
+}
+
+// Tries every roll of the die one after the other, instead of grouping them by their sum
+fn compute_outcomes_simple_aux(
+    cache: &mut BTreeMap<ScoreState, [u64; 2]>,
+    rules: &Rules,
+    universes: u64,
+    state: ScoreState,
+) -> Result<[u64; 2], GameError> {
+    if let Some(r) = cache.get(&state) {
+        return Ok(*r);
+    }
+
+    let mut n_wins = [0, 0];
+    let faces = rules.die_faces as u64;
+
+    for mut rolls in 0..universes {
+        let mut offset = 0;
+        for _ in 0..rules.rolls {
+            offset += (rolls % faces) as usize + 1;
+            rolls /= faces;
+        }
+
+        let sub_wins = match rules.advance(&state, offset) {
+            None => win(&state),
+            Some(new_state) => compute_outcomes_simple_aux(cache, rules, universes, new_state)?,
+        };
+        add_wins(&mut n_wins, 1, sub_wins)?;
+    }
+
+    cache.insert(state, n_wins);
+    Ok(n_wins)
+}
+
+fn compute_outcomes_aux(
+    cache: &mut BTreeMap<ScoreState, [u64; 2]>,
+    rules: &Rules,
+    dices_outcomes: &[u64],
+    state: ScoreState,
+) -> Result<[u64; 2], GameError> {
+    if let Some(r) = cache.get(&state) {
+        return Ok(*r);
+    }
+
+    let mut n_wins = [0, 0];
+    for (offset, &count) in dices_outcomes.iter().enumerate().filter(|(_, &c)| c > 0) {
+        let sub_wins = match rules.advance(&state, offset) {
+            None => win(&state),
+            Some(new_state) => compute_outcomes_aux(cache, rules, dices_outcomes, new_state)?,
+        };
+        add_wins(&mut n_wins, count, sub_wins)?;
+    }
+
+    cache.insert(state, n_wins);
+    Ok(n_wins)
+}
+
+/// Number of universes in which each player wins, from `state`. The counts grow quickly
+/// with the target score and the number of outcomes of a turn, and are an error when
+/// they do not fit in 64 bits.
+pub fn compute_outcomes(rules: &Rules, state: ScoreState) -> Result<[u64; 2], GameError> {
+    rules.check_state(&state)?;
+    let mut cache = BTreeMap::<ScoreState, [u64; 2]>::new();
+
+    compute_outcomes_aux(&mut cache, rules, &rules.roll_distribution()?, state)
+}
+
+pub fn compute_outcomes_simple(rules: &Rules, state: ScoreState) -> Result<[u64; 2], GameError> {
+    rules.check_state(&state)?;
+    let universes = (rules.die_faces as u64)
+        .checked_pow(rules.rolls as u32)
+        .ok_or(GameError::Overflow)?;
+    let mut cache = BTreeMap::<ScoreState, [u64; 2]>::new();
+
+    compute_outcomes_simple_aux(&mut cache, rules, universes, state)
+}
+
+fn parse_positions(input: &str) -> Result<[u16; 2], ParseError> {
+    let mut positions = input.lines().map(|l| {
+        let (_, p) = l
+            .rsplit_once(": ")
+            .ok_or_else(|| ParseError::new(input, l, "Player <n> starting position: <p>"))?;
+        match parse_token::<u16>(input, p, "a position")? {
+            p @ 1..=10 => Ok(p - 1),
+            _ => Err(ParseError::new(input, p, "a position between 1 and 10")),
+        }
+    });
+    let mut next_position = || {
+        positions
+            .next()
+            .unwrap_or_else(|| Err(ParseError::at_end(input, "a second player")))
+    };
+
+    Ok([next_position()?, next_position()?])
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, SolveError> {
+    let origin = ScoreState {
+        positions: parse_positions(input)?,
+        scores: [0, 0],
+        player: 0,
+    };
+
+    let outcomes = compute_outcomes(&Rules::default(), origin)
+        .map_err(|e| SolveError::Evaluation(e.to_string()))?;
+    Ok(outcomes[0].max(outcomes[1]).into())
+}
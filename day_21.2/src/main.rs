@@ -1,101 +1,59 @@
+use std::env;
+use std::process;
 use std::time::Instant;
-use std::{collections::BTreeMap, env};
 
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
-struct ScoreState {
-    positions: [u16; 2],
-    scores: [u16; 2],
-    player: u8,
-}
-
-fn compute_outcomes_simple_aux(
-    cache: &mut BTreeMap<ScoreState, [u64; 2]>,
-    state: ScoreState,
-) -> [u64; 2] {
-    if let Some(r) = cache.get(&state) {
-        *r
-    } else {
-        let mut n_wins = [0, 0];
-        (1..=3).for_each(|d1| {
-            (1..=3).for_each(|d2| {
-                (1..=3).for_each(|d3| {
-                    let offset = d1 + d2 + d3;
-                    let p = state.player as usize;
-                    let new_pos = (state.positions[p] + (offset as u16)) % 10;
-                    let new_score = state.scores[p] + new_pos + 1;
-
-                    if new_score >= 21 {
-                        n_wins[p] += 1;
-                    } else {
-                        let mut new_state = state.clone();
-                        new_state.scores[p] = new_score;
-                        new_state.positions[p] = new_pos;
-                        new_state.player = if p == 0 { 1 } else { 0 };
-                        let sub_wins = compute_outcomes_aux(cache, new_state);
-
-                        n_wins[0] += sub_wins[0];
-                        n_wins[1] += sub_wins[1];
-                    }
-                });
-            });
-        });
-
-        cache.insert(state, n_wins);
-        n_wins
-    }
-}
-
-fn compute_outcomes_aux(cache: &mut BTreeMap<ScoreState, [u64; 2]>, state: ScoreState) -> [u64; 2] {
-    if let Some(r) = cache.get(&state) {
-        *r
-    } else {
-        let mut n_wins = [0, 0];
-
-        let dices_outcomes = [0, 0, 0, 1, 3, 6, 7, 6, 3, 1];
-        dices_outcomes
-            .iter()
-            .enumerate()
-            .filter(|(_, &c)| c > 0)
-            .for_each(|(offset, count)| {
-                let p = state.player as usize;
-                let new_pos = (state.positions[p] + (offset as u16)) % 10;
-                let new_score = state.scores[p] + new_pos + 1;
+use day_21_2::{compute_outcomes, compute_outcomes_simple, GameError, Rules, ScoreState};
 
-                if new_score >= 21 {
-                    n_wins[p] += count;
-                } else {
-                    let mut new_state = state.clone();
-                    new_state.scores[p] = new_score;
-                    new_state.positions[p] = new_pos;
-                    new_state.player = if p == 0 { 1 } else { 0 };
-                    let sub_wins = compute_outcomes_aux(cache, new_state);
-
-                    n_wins[0] += count * sub_wins[0];
-                    n_wins[1] += count * sub_wins[1];
-                }
-            });
-        cache.insert(state, n_wins);
-        n_wins
-    }
+fn usage() -> ! {
+    eprintln!(
+        "Usage: day_21_2 <position 1> <position 2> \
+         [--board <spaces>] [--target <score>] [--faces <faces>] [--rolls <rolls>] [--check]"
+    );
+    process::exit(2);
 }
 
-fn compute_outcomes(state: ScoreState) -> [u64; 2] {
-    let mut cache = BTreeMap::<ScoreState, [u64; 2]>::new();
-
-    compute_outcomes_aux(&mut cache, state)
+fn fail(e: GameError) -> ! {
+    eprintln!("Cannot play this game: {}", e);
+    process::exit(1);
 }
 
-fn compute_outcomes_simple(state: ScoreState) -> [u64; 2] {
-    let mut cache = BTreeMap::<ScoreState, [u64; 2]>::new();
-
-    compute_outcomes_simple_aux(&mut cache, state)
-}
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let positions = [
-        args[1].parse::<u16>().unwrap() - 1,
-        args[2].parse::<u16>().unwrap() - 1,
-    ];
+    if args.len() < 3 {
+        usage();
+    }
+
+    let mut rules = Rules::default();
+    let mut check = false;
+    let mut options = args[3..].iter();
+    while let Some(option) = options.next() {
+        if option == "--check" {
+            check = true;
+            continue;
+        }
+        let value = match options.next().map(|v| v.parse::<u16>()) {
+            Some(Ok(v)) if v > 0 => v,
+            _ => usage(),
+        };
+        match option.as_str() {
+            "--board" => rules.board_size = value,
+            "--target" => rules.target = value,
+            "--faces" => rules.die_faces = value,
+            "--rolls" => rules.rolls = value,
+            _ => usage(),
+        }
+    }
+
+    let mut positions = [0; 2];
+    for (position, arg) in positions.iter_mut().zip(&args[1..3]) {
+        *position = match arg.parse::<u16>() {
+            Ok(p) if (1..=rules.board_size).contains(&p) => p - 1,
+            _ => {
+                eprintln!("Positions are between 1 and {}", rules.board_size);
+                process::exit(2);
+            }
+        };
+    }
 
     let origin = ScoreState {
         positions,
@@ -103,15 +61,16 @@ fn main() {
         player: 0,
     };
 
-    let origin_clone = origin.clone();
-
     let current = Instant::now();
-    let outcomes = compute_outcomes(origin);
+    let outcomes = compute_outcomes(&rules, origin.clone()).unwrap_or_else(|e| fail(e));
     let duration = current.elapsed();
     println!("{:?}, duration {:?}", outcomes, duration);
 
-    let current = Instant::now();
-    let outcomes = compute_outcomes_simple(origin_clone);
-    let duration = current.elapsed();
-    println!("{:?}, duration {:?}", outcomes, duration);
+    // cross-checks the result by trying every roll, which takes faces^rolls steps a turn
+    if check {
+        let current = Instant::now();
+        let outcomes = compute_outcomes_simple(&rules, origin).unwrap_or_else(|e| fail(e));
+        let duration = current.elapsed();
+        println!("{:?}, duration {:?}", outcomes, duration);
+    }
 }
@@ -0,0 +1,134 @@
+use aoc_common::{parse_token, Answer, ParseError};
+use std::ops::RangeInclusive;
+
+fn parse_range(input: &str, s: &str, axis: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let r = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| ParseError::new(input, s, format!("{}=<min>..<max>", axis)))?;
+    let (min_s, max_s) = r
+        .split_once("..")
+        .ok_or_else(|| ParseError::new(input, r, "<min>..<max>"))?;
+
+    Ok(RangeInclusive::new(
+        parse_token(input, min_s, "a number")?,
+        parse_token(input, max_s, "a number")?,
+    ))
+}
+
+type Step = (bool, [RangeInclusive<isize>; 3]);
+
+fn parse_step(input: &str, l: &str) -> Result<Step, ParseError> {
+    let (left, right) = l
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(input, l, "on or off followed by a cuboid"))?;
+    let new_val = match left {
+        "on" => true,
+        "off" => false,
+        _ => return Err(ParseError::new(input, left, "on or off")),
+    };
+
+    let mut range_iter = right.split(',');
+    let mut next_range = |axis: &str| {
+        let s = range_iter.next().ok_or_else(|| {
+            ParseError::new(
+                input,
+                &right[right.len()..],
+                format!("a range for {}", axis),
+            )
+        })?;
+        parse_range(input, s, axis)
+    };
+
+    Ok((
+        new_val,
+        [next_range("x")?, next_range("y")?, next_range("z")?],
+    ))
+}
+
+fn offset_range(
+    initial_range: &RangeInclusive<isize>,
+    offset: usize,
+) -> Option<RangeInclusive<usize>> {
+    let min = initial_range.start() + (offset as isize);
+    let max = initial_range.end() + (offset as isize);
+
+    if min < 0 || max as usize > 2 * offset {
+        None
+    } else {
+        assert!(min >= 0);
+        assert!(min as usize <= 2 * offset);
+        assert!(max >= 0);
+        assert!(max as usize <= 2 * offset);
+
+        Some(RangeInclusive::new(min as usize, max as usize))
+    }
+}
+
+fn set_grid(
+    grid: &mut [Vec<Vec<bool>>],
+    new_value: bool,
+    x_range: RangeInclusive<usize>,
+    y_range: RangeInclusive<usize>,
+    z_range: RangeInclusive<usize>,
+) {
+    for x in x_range {
+        for y in y_range.clone() {
+            for z in z_range.clone() {
+                grid[z][y][x] = new_value;
+            }
+        }
+    }
+}
+
+// only the cubes in [-pb_size, pb_size]^3 are considered
+// if no pb_size is given, the whole reactor is considered
+pub fn count_on_cubes(input: &str, pb_size: Option<usize>) -> Result<usize, ParseError> {
+    let mut max_coord: usize = 0;
+    let ranges: Vec<_> = input
+        .lines()
+        .map(|l| {
+            let (new_val, [x_range, y_range, z_range]) = parse_step(input, l)?;
+
+            max_coord = max_coord
+                .max(x_range.start().unsigned_abs())
+                .max(x_range.end().unsigned_abs());
+            max_coord = max_coord
+                .max(y_range.start().unsigned_abs())
+                .max(y_range.end().unsigned_abs());
+            max_coord = max_coord
+                .max(z_range.start().unsigned_abs())
+                .max(z_range.end().unsigned_abs());
+            Ok(((x_range, y_range, z_range), new_val))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let pb_size = pb_size.unwrap_or(max_coord);
+
+    let size = 2 * pb_size + 1;
+    let mut grid = vec![vec![vec![false; size]; size]; size];
+
+    ranges
+        .iter()
+        .for_each(|((x_range, y_range, z_range), new_val)| {
+            let x_range = offset_range(x_range, pb_size);
+            let y_range = offset_range(y_range, pb_size);
+            let z_range = offset_range(z_range, pb_size);
+            if let (Some(x_range), Some(y_range), Some(z_range)) = (x_range, y_range, z_range) {
+                set_grid(&mut grid, *new_val, x_range, y_range, z_range);
+            }
+        });
+
+    Ok(grid
+        .iter()
+        .map(|l| {
+            l.iter()
+                .map(|c| c.iter().filter(|b| **b).count())
+                .sum::<usize>()
+        })
+        .sum())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    count_on_cubes(input, Some(50)).map(Answer::from)
+}
@@ -0,0 +1,238 @@
+use aoc_common::{parse_token, Answer, ParseError};
+use std::{iter, ops::RangeInclusive};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Area {
+    x_range: RangeInclusive<isize>,
+    y_range: RangeInclusive<isize>,
+    z_range: RangeInclusive<isize>,
+    value: bool,
+}
+
+impl Area {
+    fn size(&self) -> usize {
+        let x_size = (self.x_range.end() - self.x_range.start()) as usize + 1;
+        let y_size = (self.y_range.end() - self.y_range.start()) as usize + 1;
+        let z_size = (self.z_range.end() - self.z_range.start()) as usize + 1;
+
+        x_size * y_size * z_size
+    }
+
+    fn intersects(&self, other: &Area) -> bool {
+        let x_intersection = intersects(&self.x_range, &other.x_range);
+        let y_intersection = intersects(&self.y_range, &other.y_range);
+        let z_intersection = intersects(&self.z_range, &other.z_range);
+
+        x_intersection && y_intersection && z_intersection
+    }
+
+    fn is_contained_by(&self, other: &Area) -> bool {
+        is_contained_by(&self.x_range, &other.x_range)
+            && is_contained_by(&self.y_range, &other.y_range)
+            && is_contained_by(&self.z_range, &other.z_range)
+    }
+
+    fn cut_x_plane(&self, cut: isize, cut_min: bool) -> impl Iterator<Item = Area> {
+        let new_x_ranges = cut_interval(&self.x_range, cut, cut_min);
+        let y_range = self.y_range.clone();
+        let z_range = self.z_range.clone();
+        let value = self.value;
+        new_x_ranges.into_iter().map(move |x_range| Area {
+            x_range,
+            y_range: y_range.clone(),
+            z_range: z_range.clone(),
+            value,
+        })
+    }
+    fn cut_y_plane(&self, cut: isize, cut_min: bool) -> impl Iterator<Item = Area> {
+        let new_y_ranges = cut_interval(&self.y_range, cut, cut_min);
+        let x_range = self.x_range.clone();
+        let z_range = self.z_range.clone();
+        let value = self.value;
+        new_y_ranges.into_iter().map(move |y_range| Area {
+            x_range: x_range.clone(),
+            y_range,
+            z_range: z_range.clone(),
+            value,
+        })
+    }
+    fn cut_z_plane(&self, cut: isize, cut_min: bool) -> impl Iterator<Item = Area> {
+        let new_z_ranges = cut_interval(&self.z_range, cut, cut_min);
+        let x_range = self.x_range.clone();
+        let y_range = self.y_range.clone();
+        let value = self.value;
+        new_z_ranges.into_iter().map(move |z_range| Area {
+            x_range: x_range.clone(),
+            y_range: y_range.clone(),
+            z_range,
+            value,
+        })
+    }
+
+    fn cut_area(&self, cut: &Area) -> Box<dyn Iterator<Item = Area>> {
+        if self.intersects(cut) {
+            let x_start = *cut.x_range.start();
+            let x_end = *cut.x_range.end();
+            let y_start = *cut.y_range.start();
+            let y_end = *cut.y_range.end();
+            let z_start = *cut.z_range.start();
+            let z_end = *cut.z_range.end();
+            let new_areas = self.cut_x_plane(x_start, false);
+            let new_areas = new_areas
+                .into_iter()
+                .flat_map(move |a| a.cut_x_plane(x_end, true));
+            let new_areas = new_areas
+                .into_iter()
+                .flat_map(move |a| a.cut_y_plane(y_start, false));
+            let new_areas = new_areas
+                .into_iter()
+                .flat_map(move |a| a.cut_y_plane(y_end, true));
+            let new_areas = new_areas
+                .into_iter()
+                .flat_map(move |a| a.cut_z_plane(z_start, false));
+            Box::new(
+                new_areas
+                    .into_iter()
+                    .flat_map(move |a| a.cut_z_plane(z_end, true)),
+            )
+        } else {
+            Box::new(iter::once(self.clone()))
+        }
+    }
+
+    fn substract_area(self, cut: Area) -> impl Iterator<Item = Area> {
+        let new_areas = self.cut_area(&cut);
+
+        new_areas.filter(move |a| !a.is_contained_by(&cut))
+        //         .cloned()
+        // let mut res = Vec::<Area>::with_capacity(new_areas.len());
+        // res.extend(
+        //     new_areas
+        //         .iter()
+        //         .filter(|a| !a.is_contained_by(cut))
+        //         .cloned(),
+        // );
+
+        // res
+    }
+}
+
+fn parse_range(input: &str, s: &str, axis: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let r = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| ParseError::new(input, s, format!("{}=<min>..<max>", axis)))?;
+    let (min_s, max_s) = r
+        .split_once("..")
+        .ok_or_else(|| ParseError::new(input, r, "<min>..<max>"))?;
+
+    Ok(RangeInclusive::new(
+        parse_token(input, min_s, "a number")?,
+        parse_token(input, max_s, "a number")?,
+    ))
+}
+
+type Step = (bool, [RangeInclusive<isize>; 3]);
+
+fn parse_step(input: &str, l: &str) -> Result<Step, ParseError> {
+    let (left, right) = l
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(input, l, "on or off followed by a cuboid"))?;
+    let new_val = match left {
+        "on" => true,
+        "off" => false,
+        _ => return Err(ParseError::new(input, left, "on or off")),
+    };
+
+    let mut range_iter = right.split(',');
+    let mut next_range = |axis: &str| {
+        let s = range_iter.next().ok_or_else(|| {
+            ParseError::new(
+                input,
+                &right[right.len()..],
+                format!("a range for {}", axis),
+            )
+        })?;
+        parse_range(input, s, axis)
+    };
+
+    Ok((
+        new_val,
+        [next_range("x")?, next_range("y")?, next_range("z")?],
+    ))
+}
+
+// true if s contains r
+pub fn is_contained_by(r: &RangeInclusive<isize>, s: &RangeInclusive<isize>) -> bool {
+    s.start() <= r.start() && s.end() >= r.end()
+}
+
+pub fn intersects(s: &RangeInclusive<isize>, r: &RangeInclusive<isize>) -> bool {
+    s.end() >= r.start() && s.start() <= r.end()
+}
+
+pub fn cut_interval(
+    interval: &RangeInclusive<isize>,
+    cut: isize,
+    cut_min: bool,
+) -> Vec<RangeInclusive<isize>> {
+    if (cut < *interval.start() && cut < *interval.end())
+        || (cut > *interval.start() && cut > *interval.end())
+        || (interval.start() == interval.end())
+    {
+        vec![interval.clone()]
+    } else if cut_min {
+        if cut == *interval.end() {
+            vec![interval.clone()]
+        } else {
+            vec![
+                RangeInclusive::new(*interval.start(), cut),
+                RangeInclusive::new(cut + 1, *interval.end()),
+            ]
+        }
+    } else {
+        // !cut_min
+        if cut == *interval.start() {
+            vec![interval.clone()]
+        } else {
+            vec![
+                RangeInclusive::new(*interval.start(), cut - 1),
+                RangeInclusive::new(cut, *interval.end()),
+            ]
+        }
+    }
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let orders: Vec<_> = input
+        .lines()
+        .map(|l| {
+            let (new_val, [x_range, y_range, z_range]) = parse_step(input, l)?;
+
+            Ok(Area {
+                x_range,
+                y_range,
+                z_range,
+                value: new_val,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let mut on_areas = Vec::<Area>::new();
+
+    for order in orders {
+        let new_on = on_areas
+            .into_iter()
+            .flat_map(|a| a.substract_area(order.clone()))
+            .collect();
+
+        on_areas = new_on;
+
+        if order.value {
+            // 'ON' order
+            on_areas.push(order);
+        }
+    }
+
+    Ok(on_areas.iter().map(|a| a.size()).sum::<usize>().into())
+}
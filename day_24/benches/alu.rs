@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_24::{model_digits, monad, parse_program, Alu, CompiledProgram, Register};
+
+const MODEL_NUMBER: u64 = 99799212949967;
+
+fn run_monad(c: &mut Criterion) {
+    let program = parse_program(include_str!("../input")).unwrap();
+    let compiled = CompiledProgram::new(&program);
+    let digits = model_digits(MODEL_NUMBER);
+    let mut hand_written = [0; 14];
+    for (d, &digit) in hand_written.iter_mut().zip(&digits) {
+        *d = digit as u32;
+    }
+
+    let mut group = c.benchmark_group("monad");
+    group.bench_function("interpreter", |b| {
+        b.iter(|| {
+            let mut alu = Alu::default();
+            alu.run(&program, black_box(&digits).iter().copied())
+                .unwrap();
+            alu.get(Register::Z)
+        })
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| {
+            let mut alu = Alu::default();
+            compiled
+                .run(&mut alu, black_box(&digits).iter().copied())
+                .unwrap();
+            alu.get(Register::Z)
+        })
+    });
+    group.bench_function("hand-written", |b| {
+        b.iter(|| monad(black_box(&hand_written)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, run_monad);
+criterion_main!(benches);
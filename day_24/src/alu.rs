@@ -0,0 +1,310 @@
+use aoc_common::{parse_lines, parse_token, ParseError};
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::new(s, s, "one of w x y z")),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(r) => Ok(Operand::Register(r)),
+            Err(_) => parse_token(s, s, "a register or a number").map(Operand::Value),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "{}", r),
+            Operand::Value(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let op = tokens
+            .next()
+            .ok_or_else(|| ParseError::at_end(s, "an instruction"))?;
+        let mut next_token =
+            |expected: &str| tokens.next().ok_or_else(|| ParseError::at_end(s, expected));
+        let register = |token: &str| token.parse().map_err(|e: ParseError| e.within(s, token));
+        let operand = |token: &str| token.parse().map_err(|e: ParseError| e.within(s, token));
+
+        let instruction = match op {
+            "inp" => Instruction::Inp(register(next_token("a register")?)?),
+            "add" | "mul" | "div" | "mod" | "eql" => {
+                let a = register(next_token("a register")?)?;
+                let b = operand(next_token("a register or a number")?)?;
+                match op {
+                    "add" => Instruction::Add(a, b),
+                    "mul" => Instruction::Mul(a, b),
+                    "div" => Instruction::Div(a, b),
+                    "mod" => Instruction::Mod(a, b),
+                    _ => Instruction::Eql(a, b),
+                }
+            }
+            _ => return Err(ParseError::new(s, op, "one of inp add mul div mod eql")),
+        };
+
+        match tokens.next() {
+            Some(extra) => Err(ParseError::new(s, extra, "the end of the instruction")),
+            None => Ok(instruction),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Inp(a) => write!(f, "inp {}", a),
+            Instruction::Add(a, b) => write!(f, "add {} {}", a, b),
+            Instruction::Mul(a, b) => write!(f, "mul {} {}", a, b),
+            Instruction::Div(a, b) => write!(f, "div {} {}", a, b),
+            Instruction::Mod(a, b) => write!(f, "mod {} {}", a, b),
+            Instruction::Eql(a, b) => write!(f, "eql {} {}", a, b),
+        }
+    }
+}
+
+impl Instruction {
+    /// The register written by the instruction.
+    pub fn destination(self) -> Register {
+        match self {
+            Instruction::Inp(a)
+            | Instruction::Add(a, _)
+            | Instruction::Mul(a, _)
+            | Instruction::Div(a, _)
+            | Instruction::Mod(a, _)
+            | Instruction::Eql(a, _) => a,
+        }
+    }
+
+    // Registers read by the instruction before it writes its destination. `mul a 0` does
+    // not depend on the value of `a`.
+    pub(crate) fn reads(self) -> Vec<Register> {
+        let (a, b) = match self {
+            Instruction::Inp(_) | Instruction::Mul(_, Operand::Value(0)) => return vec![],
+            Instruction::Add(a, b)
+            | Instruction::Mul(a, b)
+            | Instruction::Div(a, b)
+            | Instruction::Mod(a, b)
+            | Instruction::Eql(a, b) => (a, b),
+        };
+
+        match b {
+            Operand::Register(b) => vec![a, b],
+            Operand::Value(_) => vec![a],
+        }
+    }
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
+}
+
+/// A crash of the ALU, at the instruction with the given index in the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluError {
+    DivisionByZero {
+        at: usize,
+    },
+    /// `mod a b` with `a < 0` or `b <= 0`.
+    NegativeModulo {
+        at: usize,
+    },
+    MissingInput {
+        at: usize,
+    },
+    /// A result that does not fit in 64 bits.
+    Overflow {
+        at: usize,
+    },
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AluError::DivisionByZero { at } => write!(f, "instruction {}: division by zero", at),
+            AluError::NegativeModulo { at } => {
+                write!(
+                    f,
+                    "instruction {}: mod of a negative number or by a non-positive one",
+                    at
+                )
+            }
+            AluError::MissingInput { at } => write!(f, "instruction {}: no input left", at),
+            AluError::Overflow { at } => write!(f, "instruction {}: overflow", at),
+        }
+    }
+}
+
+impl Error for AluError {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Alu {
+    pub(crate) registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, r: Register) -> i64 {
+        self.registers[r.index()]
+    }
+
+    pub fn set(&mut self, r: Register, value: i64) {
+        self.registers[r.index()] = value;
+    }
+
+    fn value(&self, b: Operand) -> i64 {
+        match b {
+            Operand::Register(r) => self.get(r),
+            Operand::Value(v) => v,
+        }
+    }
+
+    /// Executes the instruction with index `at` in the program.
+    pub fn execute(
+        &mut self,
+        at: usize,
+        instruction: Instruction,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        match instruction {
+            Instruction::Inp(a) => {
+                let v = input.next().ok_or(AluError::MissingInput { at })?;
+                self.set(a, v);
+            }
+            Instruction::Add(a, b) => {
+                let v = self.get(a).checked_add(self.value(b));
+                self.set(a, v.ok_or(AluError::Overflow { at })?);
+            }
+            Instruction::Mul(a, b) => {
+                let v = self.get(a).checked_mul(self.value(b));
+                self.set(a, v.ok_or(AluError::Overflow { at })?);
+            }
+            Instruction::Div(a, b) => match self.value(b) {
+                0 => return Err(AluError::DivisionByZero { at }),
+                // only `i64::MIN / -1` overflows
+                b => {
+                    let v = self.get(a).checked_div(b);
+                    self.set(a, v.ok_or(AluError::Overflow { at })?);
+                }
+            },
+            Instruction::Mod(a, b) => match (self.get(a), self.value(b)) {
+                (a_val, b_val) if a_val < 0 || b_val <= 0 => {
+                    return Err(AluError::NegativeModulo { at })
+                }
+                (a_val, b_val) => self.set(a, a_val % b_val),
+            },
+            Instruction::Eql(a, b) => self.set(a, (self.get(a) == self.value(b)) as i64),
+        }
+        Ok(())
+    }
+
+    /// Runs the whole program, reading the `inp` values from `input`.
+    pub fn run(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let mut input = input.into_iter();
+
+        program
+            .iter()
+            .enumerate()
+            .try_for_each(|(at, &instruction)| self.execute(at, instruction, &mut input))
+    }
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let registers: Vec<String> = Register::ALL
+            .iter()
+            .map(|&r| format!("{}={}", r, self.get(r)))
+            .collect();
+        write!(f, "{}", registers.join(" "))
+    }
+}
+
+/// Digits of a 14-digit model number, most significant first.
+pub fn model_digits(model_number: u64) -> Vec<i64> {
+    model_number
+        .to_string()
+        .bytes()
+        .map(|b| (b - b'0') as i64)
+        .collect()
+}
+
+/// Whether MONAD, the program in `program`, accepts `model_number`: it must have 14 digits
+/// all different from 0, and leave 0 in `z`.
+pub fn is_valid_model_number(program: &[Instruction], model_number: u64) -> Result<bool, AluError> {
+    let digits = model_digits(model_number);
+    if digits.len() != 14 || digits.contains(&0) {
+        return Ok(false);
+    }
+
+    let mut alu = Alu::default();
+    alu.run(program, digits)?;
+    Ok(alu.get(Register::Z) == 0)
+}
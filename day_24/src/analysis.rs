@@ -0,0 +1,219 @@
+use crate::alu::{Instruction, Operand, Register};
+use std::{error::Error, fmt};
+
+// MONAD is made of one block per digit, all following this template with only three
+// parameters changing. `z` is used as a stack of base 26 numbers:
+// - when `div z 1` (a push block), the check is always larger than 9, so the comparison
+//   with the digit always fails, and `w + offset` is pushed on the stack;
+// - when `div z 26` (a pop block), the top of the stack is popped, and something is
+//   pushed back unless the popped value plus the check equals the digit.
+// As the stack must be empty at the end, every pop block must match its push block.
+// `w + offset` must be a base 26 digit for the stack to hold, whatever the digit `w`.
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    Fixed(Instruction),
+    DivZ,
+    Check,
+    Offset,
+}
+
+const OFFSETS: std::ops::RangeInclusive<i64> = -1..=16;
+
+// Model numbers are returned as `u64`, which holds any 19 digits
+const MAX_DIGITS: usize = 19;
+
+const BLOCK: [Slot; 18] = {
+    use Instruction::*;
+    use Operand::{Register as R, Value as V};
+    use Register::*;
+    use Slot::*;
+
+    [
+        Fixed(Inp(W)),
+        Fixed(Mul(X, V(0))),
+        Fixed(Add(X, R(Z))),
+        Fixed(Mod(X, V(26))),
+        DivZ,
+        Check,
+        Fixed(Eql(X, R(W))),
+        Fixed(Eql(X, V(0))),
+        Fixed(Mul(Y, V(0))),
+        Fixed(Add(Y, V(25))),
+        Fixed(Mul(Y, R(X))),
+        Fixed(Add(Y, V(1))),
+        Fixed(Mul(Z, R(Y))),
+        Fixed(Mul(Y, V(0))),
+        Fixed(Add(Y, R(W))),
+        Offset,
+        Fixed(Mul(Y, R(X))),
+        Fixed(Add(Z, R(Y))),
+    ]
+};
+
+/// `digit[popped] = digit[pushed] + offset`, for digits indexed from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub pushed: usize,
+    pub popped: usize,
+    pub offset: i64,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "d{} = d{}", self.popped, self.pushed)?;
+        match self.offset {
+            0 => Ok(()),
+            o if o > 0 => write!(f, " + {}", o),
+            o => write!(f, " - {}", -o),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnalysisError {
+    /// The instruction with index `at` does not follow the MONAD block template.
+    NotMonad { at: usize, expected: String },
+    /// There are more push blocks than pop blocks, or the other way around.
+    Unbalanced,
+    /// No pair of digits from 1 to 9 satisfies the constraint.
+    Unsatisfiable(Constraint),
+}
+
+impl fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnalysisError::NotMonad { at, expected } => {
+                write!(f, "instruction {}: expected {}", at, expected)
+            }
+            AnalysisError::Unbalanced => {
+                write!(f, "the numbers of push and pop blocks do not match")
+            }
+            AnalysisError::Unsatisfiable(c) => write!(f, "no digits satisfy {}", c),
+        }
+    }
+}
+
+impl Error for AnalysisError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monad {
+    pub digits: usize,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Monad {
+    /// Recognises the blocks of `program` and derives the relations between the digits
+    /// of valid model numbers.
+    pub fn analyse(program: &[Instruction]) -> Result<Self, AnalysisError> {
+        let mut stack = Vec::new();
+        let mut constraints = Vec::new();
+        let digits = program.len().div_ceil(BLOCK.len()).max(1);
+        if digits > MAX_DIGITS {
+            return Err(AnalysisError::NotMonad {
+                at: MAX_DIGITS * BLOCK.len(),
+                expected: format!("at most {} blocks", MAX_DIGITS),
+            });
+        }
+
+        for digit in 0..digits {
+            let start = digit * BLOCK.len();
+            let mut div = 0;
+            let mut check = 0;
+            let mut offset = 0;
+
+            for (i, slot) in BLOCK.iter().enumerate() {
+                let at = start + i;
+                let not_monad = |expected: String| AnalysisError::NotMonad { at, expected };
+                let instruction = *program
+                    .get(at)
+                    .ok_or_else(|| not_monad(format!("{} more instructions", BLOCK.len() - i)))?;
+
+                match (slot, instruction) {
+                    (Slot::Fixed(expected), instruction) if instruction == *expected => (),
+                    (Slot::Fixed(expected), _) => return Err(not_monad(format!("`{}`", expected))),
+                    (Slot::DivZ, Instruction::Div(Register::Z, Operand::Value(v @ 1)))
+                    | (Slot::DivZ, Instruction::Div(Register::Z, Operand::Value(v @ 26))) => {
+                        div = v
+                    }
+                    (Slot::DivZ, _) => return Err(not_monad("`div z 1` or `div z 26`".into())),
+                    (Slot::Check, Instruction::Add(Register::X, Operand::Value(v)))
+                        if div == 26 || v > 9 =>
+                    {
+                        check = v
+                    }
+                    (Slot::Check, _) => {
+                        let expected = if div == 1 {
+                            "`add x <n>` with n > 9 after `div z 1`"
+                        } else {
+                            "`add x <n>`"
+                        };
+                        return Err(not_monad(expected.into()));
+                    }
+                    (Slot::Offset, Instruction::Add(Register::Y, Operand::Value(v)))
+                        if OFFSETS.contains(&v) =>
+                    {
+                        offset = v
+                    }
+                    (Slot::Offset, _) => {
+                        let expected = format!(
+                            "`add y <n>` with {} <= n <= {}",
+                            OFFSETS.start(),
+                            OFFSETS.end()
+                        );
+                        return Err(not_monad(expected));
+                    }
+                }
+            }
+
+            if div == 1 {
+                stack.push((digit, offset));
+            } else {
+                let (pushed, pushed_offset) = stack.pop().ok_or(AnalysisError::Unbalanced)?;
+                let constraint = Constraint {
+                    pushed,
+                    popped: digit,
+                    offset: pushed_offset + check,
+                };
+                if constraint.offset.abs() > 8 {
+                    return Err(AnalysisError::Unsatisfiable(constraint));
+                }
+                constraints.push(constraint);
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err(AnalysisError::Unbalanced);
+        }
+
+        Ok(Monad {
+            digits,
+            constraints,
+        })
+    }
+
+    // Every digit is in exactly one constraint, and the pairs are independent: pick the
+    // extremal digits for each of them.
+    fn model_number(&self, largest: bool) -> u64 {
+        let mut digits = vec![0; self.digits];
+
+        for c in &self.constraints {
+            let pushed = if largest {
+                9.min(9 - c.offset)
+            } else {
+                1.max(1 - c.offset)
+            };
+            digits[c.pushed] = pushed;
+            digits[c.popped] = pushed + c.offset;
+        }
+
+        digits.iter().fold(0, |n, &d| 10 * n + d as u64)
+    }
+
+    pub fn largest(&self) -> u64 {
+        self.model_number(true)
+    }
+
+    pub fn smallest(&self) -> u64 {
+        self.model_number(false)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::alu::{Alu, AluError, Instruction, Operand};
+
+// Instructions with their operands resolved: registers are indices, constants are folded
+// in the operation, and the checks that cannot fail are left out. `at` is the index of the
+// instruction in the original program, for errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Inp(usize, usize),
+    Set(usize, i64),
+    Copy(usize, usize),
+    AddR(usize, usize, usize),
+    AddV(usize, i64, usize),
+    MulR(usize, usize, usize),
+    MulV(usize, i64, usize),
+    DivR(usize, usize, usize),
+    DivV(usize, i64, usize),
+    ModR(usize, usize, usize),
+    ModV(usize, i64, usize),
+    EqlR(usize, usize),
+    EqlV(usize, i64),
+    Crash(AluError),
+}
+
+/// A program turned into a compact bytecode, for fast repeated runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledProgram {
+    ops: Vec<Op>,
+}
+
+impl CompiledProgram {
+    pub fn new(program: &[Instruction]) -> Self {
+        use Instruction::*;
+        use Operand::{Register as R, Value as V};
+
+        let mut ops = Vec::with_capacity(program.len());
+        let mut at = 0;
+
+        while at < program.len() {
+            let mut length = 1;
+            let op = match program[at] {
+                Inp(a) => Some(Op::Inp(a as usize, at)),
+                // `mul a 0; add a b` is a copy
+                Mul(a, V(0)) => match program.get(at + 1) {
+                    Some(&Add(c, V(v))) if c == a => {
+                        length = 2;
+                        Some(Op::Set(a as usize, v))
+                    }
+                    Some(&Add(c, R(b))) if c == a && b != a => {
+                        length = 2;
+                        Some(Op::Copy(a as usize, b as usize))
+                    }
+                    _ => Some(Op::Set(a as usize, 0)),
+                },
+                Add(_, V(0)) | Mul(_, V(1)) | Div(_, V(1)) => None,
+                Add(a, R(b)) => Some(Op::AddR(a as usize, b as usize, at)),
+                Add(a, V(v)) => Some(Op::AddV(a as usize, v, at)),
+                Mul(a, R(b)) => Some(Op::MulR(a as usize, b as usize, at)),
+                Mul(a, V(v)) => Some(Op::MulV(a as usize, v, at)),
+                Div(_, V(0)) => Some(Op::Crash(AluError::DivisionByZero { at })),
+                Div(a, R(b)) => Some(Op::DivR(a as usize, b as usize, at)),
+                Div(a, V(v)) => Some(Op::DivV(a as usize, v, at)),
+                Mod(_, V(v)) if v <= 0 => Some(Op::Crash(AluError::NegativeModulo { at })),
+                Mod(a, R(b)) => Some(Op::ModR(a as usize, b as usize, at)),
+                Mod(a, V(v)) => Some(Op::ModV(a as usize, v, at)),
+                Eql(a, R(b)) => Some(Op::EqlR(a as usize, b as usize)),
+                Eql(a, V(v)) => Some(Op::EqlV(a as usize, v)),
+            };
+
+            ops.extend(op);
+            at += length;
+        }
+
+        CompiledProgram { ops }
+    }
+
+    /// Number of operations left after compilation.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Runs the program like `Alu::run` does, reading the `inp` values from `input`.
+    pub fn run(&self, alu: &mut Alu, input: impl IntoIterator<Item = i64>) -> Result<(), AluError> {
+        let mut input = input.into_iter();
+        let r = &mut alu.registers;
+        let overflow = |at| AluError::Overflow { at };
+
+        for &op in &self.ops {
+            match op {
+                Op::Inp(a, at) => r[a] = input.next().ok_or(AluError::MissingInput { at })?,
+                Op::Set(a, v) => r[a] = v,
+                Op::Copy(a, b) => r[a] = r[b],
+                Op::AddR(a, b, at) => r[a] = r[a].checked_add(r[b]).ok_or(overflow(at))?,
+                Op::AddV(a, v, at) => r[a] = r[a].checked_add(v).ok_or(overflow(at))?,
+                Op::MulR(a, b, at) => r[a] = r[a].checked_mul(r[b]).ok_or(overflow(at))?,
+                Op::MulV(a, v, at) => r[a] = r[a].checked_mul(v).ok_or(overflow(at))?,
+                Op::DivR(a, b, at) => match r[b] {
+                    0 => return Err(AluError::DivisionByZero { at }),
+                    b => r[a] = r[a].checked_div(b).ok_or(overflow(at))?,
+                },
+                Op::DivV(a, v, at) => r[a] = r[a].checked_div(v).ok_or(overflow(at))?,
+                Op::ModR(a, b, at) => {
+                    if r[a] < 0 || r[b] <= 0 {
+                        return Err(AluError::NegativeModulo { at });
+                    }
+                    r[a] %= r[b]
+                }
+                Op::ModV(a, v, at) => {
+                    if r[a] < 0 {
+                        return Err(AluError::NegativeModulo { at });
+                    }
+                    r[a] %= v
+                }
+                Op::EqlR(a, b) => r[a] = (r[a] == r[b]) as i64,
+                Op::EqlV(a, v) => r[a] = (r[a] == v) as i64,
+                Op::Crash(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
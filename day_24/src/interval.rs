@@ -0,0 +1,119 @@
+use crate::alu::{Instruction, Operand, Register};
+
+// Range of the values a register can hold, used to reason about all the possible digits
+// at once. Inputs are assumed to be digits from 1 to 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Interval(pub i64, pub i64);
+
+impl Interval {
+    pub fn point(v: i64) -> Self {
+        Interval(v, v)
+    }
+
+    pub fn contains(self, v: i64) -> bool {
+        self.0 <= v && v <= self.1
+    }
+
+    /// The only value in the interval, if there is just one.
+    pub fn constant(self) -> Option<i64> {
+        if self.0 == self.1 {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
+
+    fn from_values(values: [i64; 4]) -> Self {
+        Interval(*values.iter().min().unwrap(), *values.iter().max().unwrap())
+    }
+
+    fn add(self, b: Self) -> Self {
+        Interval(self.0.saturating_add(b.0), self.1.saturating_add(b.1))
+    }
+
+    fn mul(self, b: Self) -> Self {
+        Interval::from_values([
+            self.0.saturating_mul(b.0),
+            self.0.saturating_mul(b.1),
+            self.1.saturating_mul(b.0),
+            self.1.saturating_mul(b.1),
+        ])
+    }
+
+    // Divisions by 0 crash the ALU, so they can be left out
+    fn div(self, b: Self) -> Option<Self> {
+        match b {
+            Interval(0, 0) => None,
+            Interval(lo, hi) if lo > 0 || hi < 0 => Some(Interval::from_values([
+                self.0 / b.0,
+                self.0 / b.1,
+                self.1 / b.0,
+                self.1 / b.1,
+            ])),
+            _ => {
+                let m = self.0.saturating_abs().max(self.1.saturating_abs());
+                Some(Interval(-m, m))
+            }
+        }
+    }
+
+    // Likewise, only the cases where `a >= 0` and `b > 0` do not crash
+    fn rem(self, b: Self) -> Option<Self> {
+        if self.1 < 0 || b.1 <= 0 {
+            None
+        } else if self.0 >= 0 && self.1 < b.0.max(1) {
+            Some(self)
+        } else {
+            Some(Interval(0, self.1.min(b.1 - 1)))
+        }
+    }
+
+    fn eql(self, b: Self) -> Self {
+        if self.0 == self.1 && b.0 == b.1 && self.0 == b.0 {
+            Interval::point(1)
+        } else if self.1 < b.0 || b.1 < self.0 {
+            Interval::point(0)
+        } else {
+            Interval(0, 1)
+        }
+    }
+}
+
+pub(crate) type Registers = [Interval; 4];
+
+pub(crate) fn operand(b: Operand, registers: &Registers) -> Interval {
+    match b {
+        Operand::Register(r) => registers[r as usize],
+        Operand::Value(v) => Interval::point(v),
+    }
+}
+
+/// Runs `instruction` on all the values in `registers`. False if it crashes whatever
+/// these values.
+pub(crate) fn execute(registers: &mut Registers, instruction: Instruction) -> bool {
+    let a = |r: Register, registers: &Registers| registers[r as usize];
+    let result = match instruction {
+        Instruction::Inp(_) => Some(Interval(1, 9)),
+        Instruction::Add(r, b) => Some(a(r, registers).add(operand(b, registers))),
+        Instruction::Mul(r, b) => Some(a(r, registers).mul(operand(b, registers))),
+        Instruction::Div(r, b) => a(r, registers).div(operand(b, registers)),
+        Instruction::Mod(r, b) => a(r, registers).rem(operand(b, registers)),
+        Instruction::Eql(r, b) => Some(a(r, registers).eql(operand(b, registers))),
+    };
+    match result {
+        Some(v) => {
+            registers[instruction.destination() as usize] = v;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether `instruction` crashes for some of the values in `registers`.
+pub(crate) fn may_crash(registers: &Registers, instruction: Instruction) -> bool {
+    match instruction {
+        Instruction::Div(_, b) => operand(b, registers).contains(0),
+        Instruction::Mod(a, b) => registers[a as usize].0 < 0 || operand(b, registers).0 <= 0,
+        _ => false,
+    }
+}
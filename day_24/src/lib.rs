@@ -0,0 +1,334 @@
+mod alu;
+mod analysis;
+mod compile;
+mod interval;
+mod search;
+mod simplify;
+
+pub use alu::{
+    is_valid_model_number, model_digits, parse_program, Alu, AluError, Instruction, Operand,
+    Register,
+};
+pub use analysis::{AnalysisError, Constraint, Monad};
+pub use compile::CompiledProgram;
+pub use search::{DigitSearch, TooManyDigits};
+pub use simplify::{simplify, PseudoCode};
+
+use aoc_common::{Answer, ParseError};
+
+// Instructions are parsed one per line, so analysis errors can point at the input
+pub fn analyse(input: &str) -> Result<Monad, ParseError> {
+    let program = parse_program(input)?;
+
+    Monad::analyse(&program).map_err(|e| match e {
+        AnalysisError::NotMonad { at, expected } => match input.lines().nth(at) {
+            Some(line) => ParseError::new(input, line, expected),
+            None => ParseError::at_end(input, expected),
+        },
+        AnalysisError::Unbalanced => {
+            ParseError::at_end(input, "as many `div z 1` blocks as `div z 26` blocks")
+        }
+        AnalysisError::Unsatisfiable(c) => ParseError::at_end(
+            input,
+            format!("a constraint satisfiable by digits from 1 to 9, not {}", c),
+        ),
+    })
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    Ok(analyse(input)?.largest().into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    Ok(analyse(input)?.smallest().into())
+}
+
+// The hand-simplified program, calling `checkpoint` with the value of z at the points
+// where it was checked
+#[allow(unused_assignments)]
+fn hand_written_monad(input: &[u32; 14], mut checkpoint: impl FnMut(i32)) -> i32 {
+    let mut w = 0;
+    let mut x = 0;
+    let mut y = 0;
+    let mut z = 0;
+
+    w = input[0] as i32;
+    x = 14;
+    x = if x == w { 0 } else { 1 };
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = w;
+    y += 12;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[1] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 13;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 6;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[2] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 12;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 4;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[3] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 14;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 5;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[4] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 13;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[5] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -7;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 4;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[6] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -13;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 15;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[7] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 10;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 14;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[8] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -7;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 6;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[9] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    x += 11;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 14;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[10] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -9;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 8;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[11] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -2;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 5;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[12] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -9;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 14;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    w = input[13] as i32;
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 26;
+    x += -14;
+    x = if x == w { 0 } else { 1 };
+    y = 0;
+    y += 25;
+    y *= x;
+    y += 1;
+    z *= y;
+    y = 0;
+    y += w;
+    y += 4;
+    y *= x;
+    z += y;
+
+    checkpoint(z);
+
+    z
+}
+
+// Value of z at the points where the hand-simplified program was checked
+pub fn monad_trace(input: &[u32; 14]) -> Vec<i32> {
+    let mut trace = Vec::with_capacity(input.len());
+    hand_written_monad(input, |z| trace.push(z));
+    trace
+}
+
+pub fn monad(input: &[u32; 14]) -> i32 {
+    hand_written_monad(input, |_| ())
+}
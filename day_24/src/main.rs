@@ -1,327 +1,106 @@
-#![allow(unused_assignments)]
+use std::{env, process};
 
-fn monad(input: &[u32; 14]) -> i32 {
-    let mut w = 0;
-    let mut x = 0;
-    let mut y = 0;
-    let mut z = 0;
+use aoc_common::{Input, ParseError};
+use day_24::{
+    analyse, model_digits, parse_program, simplify, Alu, DigitSearch, PseudoCode, Register,
+};
 
-    w = input[0] as i32;
-    x = 14;
-    x = if x == w { 0 } else { 1 };
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = w;
-    y += 12;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[1] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 13;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 6;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[2] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 12;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 4;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[3] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 14;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 5;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[4] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 13;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[5] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -7;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 4;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[6] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -13;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 15;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[7] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 10;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 14;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[8] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -7;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 6;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[9] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    x += 11;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 14;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[10] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -9;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 8;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[11] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -2;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 5;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[12] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -9;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 14;
-    y *= x;
-    z += y;
-
-    println!("{}", z);
-
-    w = input[13] as i32;
-    x = 0;
-    x += z;
-    x %= 26;
-    z /= 26;
-    x += -14;
-    x = if x == w { 0 } else { 1 };
-    y = 0;
-    y += 25;
-    y *= x;
-    y += 1;
-    z *= y;
-    y = 0;
-    y += w;
-    y += 4;
-    y *= x;
-    z += y;
+fn usage() -> ! {
+    eprintln!("Usage: day_24 <program> [--search | --simplify | <model number>]");
+    process::exit(2);
+}
 
-    println!("{}", z);
+fn print_analysis(input: &str) -> Result<(), ParseError> {
+    let monad = analyse(input)?;
 
-    z
+    println!("Constraints:");
+    monad.constraints.iter().for_each(|c| println!("  {}", c));
+    println!("Largest model number: {}", monad.largest());
+    println!("Smallest model number: {}", monad.smallest());
+    Ok(())
 }
 
-fn find_largest_monad_aux(input: &mut [u32; 14], char_index: usize) -> bool {
-    if char_index == input.len() {
-        monad(input) == 0
-    } else {
-        for c in (1..=9).rev() {
-            input[char_index] = c;
-
-            if char_index == 6 {
-                println!("{:?}", input);
-            }
-            if find_largest_monad_aux(input, char_index + 1) {
-                return true;
-            }
+fn print_search(input: &str) -> Result<(), ParseError> {
+    let program = parse_program(input)?;
+    let search = DigitSearch::new(&program).map_err(|e| {
+        let expected = format!("at most {} inp instructions", DigitSearch::MAX_DIGITS);
+        match input.lines().nth(e.at) {
+            Some(line) => ParseError::new(input, line, expected),
+            None => ParseError::at_end(input, expected),
         }
-        false
-    }
+    })?;
+    let print = |name, n: Option<u64>| match n {
+        Some(n) => println!("{} model number: {}", name, n),
+        None => println!("No valid model number"),
+    };
+
+    print("Largest", search.largest());
+    print("Smallest", search.smallest());
+    Ok(())
 }
 
-fn find_smallest_monad_aux(input: &mut [u32; 14], char_index: usize) -> bool {
-    if char_index == input.len() {
-        monad(input) == 0
-    } else {
-        for c in (1..=9) {
-            input[char_index] = c;
-
-            if char_index == 6 {
-                println!("{:?}", input);
-            }
-            if find_smallest_monad_aux(input, char_index + 1) {
-                return true;
-            }
-        }
-        false
-    }
+fn print_simplified(input: &str) -> Result<(), ParseError> {
+    let program = parse_program(input)?;
+    let simplified = simplify(&program);
+
+    println!(
+        "Simplified program ({} instructions out of {}):",
+        simplified.len(),
+        program.len()
+    );
+    simplified.iter().for_each(|i| println!("  {}", i));
+    println!();
+    println!("Pseudo-code:");
+    print!("{}", PseudoCode::new(&simplified));
+    Ok(())
 }
 
-// fn main() {
-//     let mut monad = [9; 14];
+fn check_model_number(input: &str, model_number: &str) -> Result<(), ParseError> {
+    let digits = match model_number.parse() {
+        Ok(n) => model_digits(n),
+        Err(_) => usage(),
+    };
+    if digits.len() != 14 || digits.contains(&0) {
+        eprintln!("A model number has 14 digits, from 1 to 9");
+        process::exit(2);
+    }
 
-//     find_largest_monad_aux(&mut monad, 0);
+    let program = parse_program(input)?;
+    let mut alu = Alu::default();
+    if let Err(e) = alu.run(&program, digits) {
+        eprintln!("ALU crashed: {}", e);
+        process::exit(1);
+    }
 
-//     println!("Last {:?}", monad);
-// }
+    println!("Registers: {}", alu);
+    println!(
+        "{} is {}a valid model number",
+        model_number,
+        if alu.get(Register::Z) == 0 {
+            ""
+        } else {
+            "not "
+        }
+    );
+    Ok(())
+}
 
 fn main() {
-    // let input = [9, 9, 7, 9, 9, 2, 1, 2, 9, 4, 9, 9, 6, 7];
-    let input = [3, 4, 1, 9, 8, 1, 1, 1, 8, 1, 6, 3, 1, 1];
-    monad(&input);
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 && args.len() != 3 {
+        usage();
+    }
+    let input = Input::read(&args[1..2]).unwrap();
+
+    let result = match args.get(2).map(|s| s.as_str()) {
+        None => print_analysis(&input.text),
+        Some("--search") => print_search(&input.text),
+        Some("--simplify") => print_simplified(&input.text),
+        Some(model_number) => check_model_number(&input.text, model_number),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", input.locate(e));
+        process::exit(1);
+    }
 }
@@ -0,0 +1,177 @@
+use crate::{
+    alu::{Alu, Instruction, Register},
+    compile::CompiledProgram,
+    interval::{self, Interval},
+};
+use std::{collections::HashSet, error::Error, fmt, iter};
+
+/// Searches the model numbers accepted by a program digit by digit, running it one `inp`
+/// block at a time.
+///
+/// Two partial runs reaching the same block with the same values in the registers that
+/// are still read later behave the same: each such state is only explored once, and
+/// states from which no digits lead to an accepted number are remembered as dead. States
+/// are also pruned when running the rest of the program on intervals of values shows that
+/// z cannot end up being 0.
+pub struct DigitSearch<'a> {
+    prelude: &'a [Instruction],
+    blocks: Vec<&'a [Instruction]>,
+    compiled: Vec<CompiledProgram>,
+    // registers live when entering each block
+    live: Vec<[bool; 4]>,
+}
+
+impl<'a> DigitSearch<'a> {
+    /// Largest number of digits of a model number fitting in a `u64`.
+    pub const MAX_DIGITS: usize = 19;
+
+    pub fn new(program: &'a [Instruction]) -> Result<Self, TooManyDigits> {
+        let mut starts: Vec<usize> = program
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| matches!(i, Instruction::Inp(_)))
+            .map(|(at, _)| at)
+            .collect();
+        if let Some(&at) = starts.get(Self::MAX_DIGITS) {
+            return Err(TooManyDigits { at });
+        }
+        let prelude = &program[..starts.first().copied().unwrap_or(program.len())];
+        starts.push(program.len());
+        let blocks: Vec<_> = starts.windows(2).map(|w| &program[w[0]..w[1]]).collect();
+
+        // only z is checked at the end of the program
+        let mut live_out = [false, false, false, true];
+        let mut live = vec![[false; 4]; blocks.len()];
+        for (b, block) in blocks.iter().enumerate().rev() {
+            let mut live_in = live_out;
+            for &instruction in block.iter().rev() {
+                live_in[instruction.destination() as usize] = false;
+                for r in instruction.reads() {
+                    live_in[r as usize] = true;
+                }
+            }
+            live[b] = live_in;
+            live_out = live_in;
+        }
+
+        Ok(DigitSearch {
+            prelude,
+            compiled: blocks.iter().map(|b| CompiledProgram::new(b)).collect(),
+            blocks,
+            live,
+        })
+    }
+
+    // Runs the rest of the program on all the possible digits at once. False if it crashes
+    // whatever the digits, or if z cannot be 0 at the end.
+    fn may_accept(&self, block: usize, alu: &Alu) -> bool {
+        let mut registers = Register::ALL.map(|r| Interval::point(alu.get(r)));
+
+        for &instruction in self.blocks[block..].iter().flat_map(|b| b.iter()) {
+            if !interval::execute(&mut registers, instruction) {
+                return false;
+            }
+        }
+
+        registers[Register::Z as usize].contains(0)
+    }
+
+    /// Number of digits read by the program.
+    pub fn digits(&self) -> usize {
+        self.blocks.len()
+    }
+
+    // Forgets the registers whose value does not matter anymore
+    fn state(&self, block: usize, alu: &Alu) -> Alu {
+        let mut state = Alu::default();
+        for r in Register::ALL {
+            if self.live[block][r as usize] {
+                state.set(r, alu.get(r));
+            }
+        }
+        state
+    }
+
+    fn explore(
+        &self,
+        block: usize,
+        alu: Alu,
+        digits: &[i64; 9],
+        dead: &mut HashSet<(usize, Alu)>,
+        model_number: &mut Vec<i64>,
+    ) -> bool {
+        if block == self.blocks.len() {
+            return alu.get(Register::Z) == 0;
+        }
+        let state = self.state(block, &alu);
+        if dead.contains(&(block, state)) {
+            return false;
+        }
+        if !self.may_accept(block, &state) {
+            dead.insert((block, state));
+            return false;
+        }
+
+        for &digit in digits {
+            let mut next = state;
+            // a crash of the ALU rejects the model number
+            let ran = self.compiled[block].run(&mut next, iter::once(digit));
+
+            if ran.is_ok() {
+                model_number.push(digit);
+                if self.explore(block + 1, next, digits, dead, model_number) {
+                    return true;
+                }
+                model_number.pop();
+            }
+        }
+
+        dead.insert((block, state));
+        false
+    }
+
+    fn find(&self, digits: [i64; 9]) -> Option<u64> {
+        let mut alu = Alu::default();
+        let mut no_input = iter::empty();
+        self.prelude
+            .iter()
+            .enumerate()
+            .try_for_each(|(at, &instruction)| alu.execute(at, instruction, &mut no_input))
+            .ok()?;
+
+        let mut model_number = Vec::new();
+        if self.explore(0, alu, &digits, &mut HashSet::new(), &mut model_number) {
+            Some(model_number.iter().fold(0, |n, &d| 10 * n + d as u64))
+        } else {
+            None
+        }
+    }
+
+    pub fn largest(&self) -> Option<u64> {
+        self.find([9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    pub fn smallest(&self) -> Option<u64> {
+        self.find([1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+}
+
+/// A program reading more digits than [`DigitSearch::MAX_DIGITS`], the `inp` instruction
+/// with index `at` being the first one too many.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyDigits {
+    pub at: usize,
+}
+
+impl fmt::Display for TooManyDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "instruction {}: model numbers have at most {} digits",
+            self.at,
+            DigitSearch::MAX_DIGITS
+        )
+    }
+}
+
+impl Error for TooManyDigits {}
@@ -0,0 +1,486 @@
+use crate::{
+    alu::{Instruction, Operand, Register},
+    interval::{self, Interval, Registers},
+};
+use std::{fmt, iter};
+
+// Replaces the register operands known to hold a single value by this value
+fn with_constant_operand(instruction: Instruction, registers: &Registers) -> Instruction {
+    let constant = |b: Operand| match b {
+        Operand::Register(r) => registers[r as usize].constant().map_or(b, Operand::Value),
+        b => b,
+    };
+
+    match instruction {
+        Instruction::Inp(a) => Instruction::Inp(a),
+        Instruction::Add(a, b) => Instruction::Add(a, constant(b)),
+        Instruction::Mul(a, b) => Instruction::Mul(a, constant(b)),
+        Instruction::Div(a, b) => Instruction::Div(a, constant(b)),
+        Instruction::Mod(a, b) => Instruction::Mod(a, constant(b)),
+        Instruction::Eql(a, b) => Instruction::Eql(a, constant(b)),
+    }
+}
+
+fn is_identity(instruction: Instruction, registers: &Registers) -> bool {
+    match instruction {
+        Instruction::Add(_, Operand::Value(0))
+        | Instruction::Mul(_, Operand::Value(1))
+        | Instruction::Div(_, Operand::Value(1)) => true,
+        Instruction::Mod(a, Operand::Value(m)) => {
+            let Interval(lo, hi) = registers[a as usize];
+            lo >= 0 && hi < m
+        }
+        _ => false,
+    }
+}
+
+fn propagate_constants(program: &[Instruction]) -> Vec<Instruction> {
+    let mut registers = [Interval::point(0); 4];
+    let mut reduced = Vec::new();
+
+    for (at, &instruction) in program.iter().enumerate() {
+        let instruction = with_constant_operand(instruction, &registers);
+        let before = registers;
+        if !interval::execute(&mut registers, instruction) {
+            // the program always crashes here, the rest does not matter
+            reduced.extend_from_slice(&program[at..]);
+            break;
+        }
+        if interval::may_crash(&before, instruction) {
+            reduced.push(instruction);
+            continue;
+        }
+
+        let a = instruction.destination();
+        let old = before[a as usize].constant();
+        match (registers[a as usize].constant(), instruction) {
+            (_, Instruction::Inp(_)) => reduced.push(instruction),
+            (Some(new), _) if old == Some(new) => (),
+            // constant-false comparisons and the like become plain assignments
+            (Some(new), Instruction::Div(..) | Instruction::Mod(..) | Instruction::Eql(..)) => {
+                if old != Some(0) {
+                    reduced.push(Instruction::Mul(a, Operand::Value(0)));
+                }
+                if new != 0 {
+                    reduced.push(Instruction::Add(a, Operand::Value(new)));
+                }
+            }
+            _ if is_identity(instruction, &before) => (),
+            _ => reduced.push(instruction),
+        }
+    }
+
+    reduced
+}
+
+// Removes the instructions whose result is never read, unless they read an input or may
+// crash the ALU
+fn remove_dead_code(program: &[Instruction]) -> Vec<Instruction> {
+    let mut registers = [Interval::point(0); 4];
+    let may_crash: Vec<bool> = program
+        .iter()
+        .map(|&instruction| {
+            let may_crash = interval::may_crash(&registers, instruction);
+            interval::execute(&mut registers, instruction);
+            may_crash
+        })
+        .collect();
+
+    // only z is checked at the end of the program
+    let mut live = [false, false, false, true];
+    let mut kept = Vec::new();
+    for (&instruction, may_crash) in program.iter().zip(may_crash).rev() {
+        let a = instruction.destination() as usize;
+        if live[a] || may_crash || matches!(instruction, Instruction::Inp(_)) {
+            live[a] = false;
+            for r in instruction.reads() {
+                live[r as usize] = true;
+            }
+            kept.push(instruction);
+        }
+    }
+
+    kept.reverse();
+    kept
+}
+
+/// Rewrites `program` with what is known of the values of the registers at each point,
+/// and removes the instructions that do not change its result: multiplications by 1,
+/// comparisons that are always false, computations of values that are never read...
+/// Inputs are assumed to be digits from 1 to 9.
+pub fn simplify(program: &[Instruction]) -> Vec<Instruction> {
+    remove_dead_code(&propagate_constants(program))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Mul,
+    Div,
+    Mod,
+}
+
+// `operand + offset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Expr(Operand, i64);
+
+impl Expr {
+    fn register(self) -> Option<Register> {
+        match self.0 {
+            Operand::Register(r) => Some(r),
+            Operand::Value(_) => None,
+        }
+    }
+
+    fn plus(self, k: i64) -> Self {
+        match self.0 {
+            Operand::Value(v) => Expr(Operand::Value(v + self.1 + k), 0),
+            b => Expr(b, self.1 + k),
+        }
+    }
+}
+
+impl From<Operand> for Expr {
+    fn from(b: Operand) -> Self {
+        Expr(b, 0)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            0 => write!(f, "{}", self.0),
+            k if k > 0 => write!(f, "{} + {}", self.0, k),
+            k => write!(f, "{} - {}", self.0, -k),
+        }
+    }
+}
+
+// `register`, or `register == b` / `register != b`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Condition {
+    register: Register,
+    compared: Option<(Operand, bool)>,
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.compared {
+            None => write!(f, "{}", self.register),
+            Some((b, true)) => write!(f, "{} == {}", self.register, b),
+            Some((b, false)) => write!(f, "{} != {}", self.register, b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Input(Register, usize),
+    Assign(Register, Expr),
+    Update(Op, Register, Expr),
+    /// `a = a == b`, or `a = a != b`
+    Compare(Register, Operand, bool),
+    Not(Register),
+    If(Condition, Vec<Statement>),
+}
+
+impl Statement {
+    // The register always written by the statement
+    fn writes(&self) -> Option<Register> {
+        match self {
+            Statement::Input(a, _)
+            | Statement::Assign(a, _)
+            | Statement::Update(_, a, _)
+            | Statement::Compare(a, _, _)
+            | Statement::Not(a) => Some(*a),
+            Statement::If(..) => None,
+        }
+    }
+
+    fn may_write(&self, r: Register) -> bool {
+        match self {
+            Statement::If(_, body) => body.iter().any(|s| s.may_write(r)),
+            s => s.writes() == Some(r),
+        }
+    }
+
+    // Registers read by the statement, without the ones in the body of an `if`
+    fn reads(&self) -> Vec<Register> {
+        let operand = |b: Operand| Expr::from(b).register();
+        match self {
+            Statement::Input(..) => vec![],
+            Statement::Assign(_, e) => e.register().into_iter().collect(),
+            Statement::Update(_, a, e) => iter::once(*a).chain(e.register()).collect(),
+            Statement::Compare(a, b, _) => iter::once(*a).chain(operand(*b)).collect(),
+            Statement::Not(a) => vec![*a],
+            Statement::If(condition, _) => iter::once(condition.register)
+                .chain(condition.compared.and_then(|(b, _)| operand(b)))
+                .collect(),
+        }
+    }
+
+    fn live_before(&self, mut live: [bool; 4]) -> [bool; 4] {
+        match self {
+            Statement::If(_, body) => {
+                let inner = live_before(body, live);
+                (0..4).for_each(|r| live[r] |= inner[r]);
+            }
+            s => live[s.writes().unwrap() as usize] = false,
+        }
+        for r in self.reads() {
+            live[r as usize] = true;
+        }
+        live
+    }
+}
+
+fn live_before(statements: &[Statement], live_out: [bool; 4]) -> [bool; 4] {
+    statements
+        .iter()
+        .rev()
+        .fold(live_out, |live, s| s.live_before(live))
+}
+
+fn translate(program: &[Instruction]) -> Vec<Statement> {
+    use Instruction::*;
+    use Operand::Value as V;
+
+    let mut registers = [Interval::point(0); 4];
+    let mut statements = Vec::new();
+    let mut inputs = 0;
+    let mut at = 0;
+
+    while at < program.len() {
+        let known = |r: Register| registers[r as usize];
+        let (statement, length) = match (program[at], program.get(at + 1).copied()) {
+            (Inp(a), _) => {
+                inputs += 1;
+                (Statement::Input(a, inputs - 1), 1)
+            }
+            // `mul a 0; add a b` is a copy
+            (Mul(a, V(0)), Some(Add(c, b))) if a == c && b != Operand::Register(a) => {
+                (Statement::Assign(a, b.into()), 2)
+            }
+            (Mul(a, V(0)), _) => (Statement::Assign(a, V(0).into()), 1),
+            (Add(a, b), _) if known(a) == Interval::point(0) => (Statement::Assign(a, b.into()), 1),
+            // `eql x 0` after a comparison is a negation
+            (Eql(a, b), Some(Eql(c, V(0)))) if a == c => (Statement::Compare(a, b, false), 2),
+            (Eql(a, V(0)), _) if known(a).0 >= 0 && known(a).1 <= 1 => (Statement::Not(a), 1),
+            (Eql(a, b), _) => (Statement::Compare(a, b, true), 1),
+            (Add(a, b), _) => (Statement::Update(Op::Add, a, b.into()), 1),
+            (Mul(a, b), _) => (Statement::Update(Op::Mul, a, b.into()), 1),
+            (Div(a, b), _) => (Statement::Update(Op::Div, a, b.into()), 1),
+            (Mod(a, b), _) => (Statement::Update(Op::Mod, a, b.into()), 1),
+        };
+
+        for &instruction in &program[at..at + length] {
+            interval::execute(&mut registers, instruction);
+        }
+        statements.push(statement);
+        at += length;
+    }
+
+    statements
+}
+
+// `t *= c`, with a boolean `c`, zeroes `t` when `c` is false. If `t` is then only used by
+// `z += t` or `z *= t + 1`, which do nothing when `t` is 0, the whole computation of `t`
+// can go under `if c`. Returns the start of the computation of `t` in `done`, `c`, and the
+// index of the use of `t` in `statements`.
+fn conditional(
+    done: &[Statement],
+    statements: &[Statement],
+    i: usize,
+) -> Option<(usize, Register, usize)> {
+    let (t, c) = match statements[i] {
+        Statement::Update(Op::Mul, t, Expr(Operand::Register(c), 0)) if t != c => (t, c),
+        _ => return None,
+    };
+    let last_write = done.iter().rev().find(|s| s.may_write(c));
+    if !matches!(
+        last_write,
+        Some(Statement::Compare(..)) | Some(Statement::Not(_))
+    ) {
+        return None;
+    }
+
+    // the definition of `t`, from its last assignment
+    let writes_t = |s: &Statement| s.writes() == Some(t) && !matches!(s, Statement::Input(..));
+    let run = done.iter().rposition(|s| !writes_t(s)).map_or(0, |p| p + 1);
+    let start = run
+        + done[run..]
+            .iter()
+            .rposition(|s| matches!(s, Statement::Assign(r, _) if *r == t))?;
+
+    let mut offset = 0;
+    let mut end = i + 1;
+    while let Some(Statement::Update(Op::Add, r, Expr(Operand::Value(k), 0))) = statements.get(end)
+    {
+        if *r != t {
+            break;
+        }
+        offset += k;
+        end += 1;
+    }
+    match statements.get(end)? {
+        Statement::Update(op, z, Expr(Operand::Register(r), 0))
+            if *r == t && *z != t && matches!((op, offset), (Op::Add, 0) | (Op::Mul, 1)) => {}
+        _ => return None,
+    }
+    if live_before(&statements[end + 1..], [false, false, false, true])[t as usize] {
+        return None;
+    }
+
+    Some((start, c, end))
+}
+
+fn extract_conditionals(statements: &[Statement]) -> Vec<Statement> {
+    let mut out: Vec<Statement> = Vec::new();
+    let mut i = 0;
+
+    while i < statements.len() {
+        match conditional(&out, statements, i) {
+            Some((start, c, end)) => {
+                let mut body: Vec<_> = out.drain(start..).collect();
+                body.extend_from_slice(&statements[i + 1..=end]);
+                let condition = Condition {
+                    register: c,
+                    compared: None,
+                };
+                // conditions on the same register next to each other are merged
+                match out.last_mut() {
+                    Some(Statement::If(previous, previous_body))
+                        if *previous == condition
+                            && !previous_body.iter().any(|s| s.may_write(c)) =>
+                    {
+                        previous_body.extend(body)
+                    }
+                    _ => out.push(Statement::If(condition, body)),
+                }
+                i = end + 1;
+            }
+            None => {
+                out.push(statements[i].clone());
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+// Inlines the temporary values used only once, and the comparisons used only by an `if`
+fn fold(statements: &[Statement], live_out: [bool; 4]) -> Vec<Statement> {
+    let mut out: Vec<Statement> = Vec::new();
+
+    for (i, statement) in statements.iter().enumerate() {
+        let live_after = live_before(&statements[i + 1..], live_out);
+        let statement = match statement {
+            Statement::If(condition, body) => Statement::If(*condition, fold(body, live_after)),
+            s => s.clone(),
+        };
+
+        let folded = match (out.last(), &statement) {
+            // `t = e; t += k` is `t = e + k`
+            (
+                Some(Statement::Assign(t, e)),
+                Statement::Update(Op::Add, u, Expr(Operand::Value(k), 0)),
+            ) if t == u => Some(Statement::Assign(*t, e.plus(*k))),
+            // `t = e; a = t + k` is `a = e + k` if `t` is not read afterwards
+            (
+                Some(Statement::Assign(t, e)),
+                Statement::Assign(a, Expr(Operand::Register(u), k)),
+            ) if t == u && !live_after[*t as usize] => Some(Statement::Assign(*a, e.plus(*k))),
+            (
+                Some(Statement::Assign(t, e)),
+                Statement::Update(op, a, Expr(Operand::Register(u), k)),
+            ) if t == u && a != t && !live_after[*t as usize] => {
+                Some(Statement::Update(*op, *a, e.plus(*k)))
+            }
+            // `x = x != b; if x { ... }` is `if x != b { ... }` if `x` is not read afterwards
+            (
+                Some(Statement::Compare(x, b, equal)),
+                Statement::If(
+                    Condition {
+                        register,
+                        compared: None,
+                    },
+                    body,
+                ),
+            ) if x == register
+                && !live_after[*x as usize]
+                && !live_before(body, live_after)[*x as usize] =>
+            {
+                let condition = Condition {
+                    register: *x,
+                    compared: Some((*b, *equal)),
+                };
+                Some(Statement::If(condition, body.clone()))
+            }
+            _ => None,
+        };
+
+        match folded {
+            Some(folded) => *out.last_mut().unwrap() = folded,
+            None => out.push(statement),
+        }
+    }
+
+    out
+}
+
+/// C-like view of a program, in the style of `simplified_input`: copies, negated
+/// comparisons and the computations only used when a comparison holds are recognised.
+pub struct PseudoCode(Vec<Statement>);
+
+impl PseudoCode {
+    pub fn new(program: &[Instruction]) -> Self {
+        let statements = extract_conditionals(&translate(program));
+        PseudoCode(fold(&statements, [false, false, false, true]))
+    }
+}
+
+fn write_statements(
+    f: &mut fmt::Formatter<'_>,
+    statements: &[Statement],
+    indent: usize,
+) -> fmt::Result {
+    let pad = "    ".repeat(indent);
+
+    for (i, statement) in statements.iter().enumerate() {
+        // one paragraph per input
+        if indent == 0 && i > 0 && matches!(statement, Statement::Input(..)) {
+            writeln!(f)?;
+        }
+        match statement {
+            Statement::Input(a, n) => writeln!(f, "{}{} = input[{}];", pad, a, n)?,
+            Statement::Assign(a, e) => writeln!(f, "{}{} = {};", pad, a, e)?,
+            Statement::Update(op, a, e) => {
+                let op = match op {
+                    Op::Add => "+",
+                    Op::Mul => "*",
+                    Op::Div => "/",
+                    Op::Mod => "%",
+                };
+                writeln!(f, "{}{} {}= {};", pad, a, op, e)?
+            }
+            Statement::Compare(a, b, equal) => {
+                let op = if *equal { "==" } else { "!=" };
+                writeln!(f, "{}{} = {} {} {};", pad, a, a, op, b)?
+            }
+            Statement::Not(a) => writeln!(f, "{}{} = !{};", pad, a, a)?,
+            Statement::If(condition, body) => {
+                writeln!(f, "{}if {} {{", pad, condition)?;
+                write_statements(f, body, indent + 1)?;
+                writeln!(f, "{}}}", pad)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for PseudoCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_statements(f, &self.0, 0)
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_common::{Answer, Grid, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cucumber {
+    None,
+    East,
+    South,
+}
+
+fn find_movable(grid: &Grid<Cucumber>, herd: Cucumber) -> Vec<((usize, usize), (usize, usize))> {
+    let direction = match herd {
+        Cucumber::East => (1, 0),
+        Cucumber::South => (0, 1),
+        Cucumber::None => unimplemented!(),
+    };
+
+    grid.positions()
+        .filter(|&p| grid[p] == herd)
+        .filter_map(|p| {
+            // the sea floor wraps around, so there is always a next position
+            let next = grid.offset(p, direction).unwrap();
+            if grid[next] == Cucumber::None {
+                Some((p, next))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn move_cucumbers(
+    grid: &mut Grid<Cucumber>,
+    cucumbers_pos: impl Iterator<Item = ((usize, usize), (usize, usize))>,
+) {
+    for (original, next) in cucumbers_pos {
+        grid[next] = grid[original];
+        grid[original] = Cucumber::None;
+    }
+}
+
+fn parse_cucumber(c: char) -> Option<Cucumber> {
+    match c {
+        '>' => Some(Cucumber::East),
+        'v' => Some(Cucumber::South),
+        '.' => Some(Cucumber::None),
+        _ => None,
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let mut grid = Grid::parse(input, parse_cucumber, "one of > v .")?.wrapping();
+
+    let mut direction = Cucumber::East;
+    let mut consecutive_move_failures = 0;
+    let mut n_steps = 0;
+
+    loop {
+        n_steps += 1;
+
+        let movables = find_movable(&grid, direction);
+        if movables.is_empty() {
+            consecutive_move_failures += 1;
+            if consecutive_move_failures >= 2 {
+                break;
+            }
+        } else {
+            move_cucumbers(&mut grid, movables.into_iter());
+            consecutive_move_failures = 0;
+        }
+        direction = match direction {
+            Cucumber::East => Cucumber::South,
+            Cucumber::South => Cucumber::East,
+            _ => unreachable!(),
+        };
+    }
+
+    n_steps += 1;
+    Ok((n_steps / 2).into())
+}
@@ -0,0 +1,126 @@
+use aoc_common::{parse_lines, Answer, ParseError};
+use std::ops::Add;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum DiagnosticBit {
+    #[default]
+    Zero,
+    One,
+}
+
+struct DiagnosticLine<const N: usize>([DiagnosticBit; N]);
+
+impl<const N: usize> DiagnosticLine<N> {
+    fn invert(&self) -> DiagnosticLine<N> {
+        let mut line = DiagnosticLine::default();
+
+        for i in 0..N {
+            match self.0[i] {
+                DiagnosticBit::Zero => line.0[i] = DiagnosticBit::One,
+                DiagnosticBit::One => line.0[i] = DiagnosticBit::Zero,
+            }
+        }
+        line
+    }
+}
+
+impl<const N: usize> From<DiagnosticLine<N>> for u64 {
+    fn from(line: DiagnosticLine<N>) -> u64 {
+        line.0.into_iter().fold(0u64, |acc, bit| match bit {
+            DiagnosticBit::Zero => 2 * acc,
+            DiagnosticBit::One => 2 * acc + 1,
+        })
+    }
+}
+
+impl<const N: usize> Default for DiagnosticLine<N> {
+    fn default() -> Self {
+        DiagnosticLine::<N>([DiagnosticBit::Zero; N])
+    }
+}
+
+struct DiagnosticAccumulator<const N: usize>([usize; N]);
+
+impl<const N: usize> DiagnosticAccumulator<N> {
+    fn get_summary_line(&self, line_count: usize) -> DiagnosticLine<N> {
+        let mut summary_line = DiagnosticLine::default();
+
+        for i in 0..N {
+            if 2 * self.0[i] > line_count {
+                // might overflow ...
+                summary_line.0[i] = DiagnosticBit::One;
+            }
+        }
+        summary_line
+    }
+}
+impl<const N: usize> Default for DiagnosticAccumulator<N> {
+    fn default() -> Self {
+        DiagnosticAccumulator::<N>([0usize; N])
+    }
+}
+
+impl<const N: usize> Add<DiagnosticLine<N>> for DiagnosticAccumulator<N> {
+    type Output = Self;
+
+    fn add(self, other: DiagnosticLine<N>) -> Self {
+        let mut r = self;
+
+        r.0.iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(acc_cell, bit)| {
+                if *bit == DiagnosticBit::One {
+                    *acc_cell += 1;
+                }
+            });
+
+        r
+    }
+}
+
+impl<const N: usize> FromStr for DiagnosticLine<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bits = s.trim();
+        if bits.len() != N {
+            return Err(ParseError::new(s, bits, format!("{} bits", N)));
+        }
+
+        let elts = bits
+            .char_indices()
+            .map(|(i, c)| match c {
+                '0' => Ok(DiagnosticBit::Zero),
+                '1' => Ok(DiagnosticBit::One),
+                _ => Err(ParseError::new(s, &bits[i..i + c.len_utf8()], "0 or 1")),
+            })
+            .collect::<Result<Vec<DiagnosticBit>, _>>()?;
+        Ok(DiagnosticLine::<N>(elts.try_into().unwrap()))
+    }
+}
+
+fn power_consumption<const N: usize>(input: &str) -> Result<u64, ParseError> {
+    let (acc, line_count) = parse_lines::<DiagnosticLine<N>>(input)?.into_iter().fold(
+        (DiagnosticAccumulator::<N>::default(), 0usize),
+        |(acc, count), line| (acc + line, count + 1),
+    );
+
+    let summary = acc.get_summary_line(line_count);
+    let inv_summary = summary.invert();
+    let gamma: u64 = summary.into();
+    let epsilon: u64 = inv_summary.into();
+
+    Ok(gamma * epsilon)
+}
+
+// The example uses 5 bits per line, the puzzle input 12
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let first_line = input.lines().next().unwrap_or("");
+    match first_line.len() {
+        5 => power_consumption::<5>(input),
+        12 => power_consumption::<12>(input),
+        _ => Err(ParseError::new(input, first_line, "5 or 12 bits")),
+    }
+    .map(Answer::from)
+}
@@ -0,0 +1,123 @@
+use aoc_common::{parse_lines, Answer, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum DiagnosticBit {
+    #[default]
+    Zero,
+    One,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DiagnosticLine<const N: usize>([DiagnosticBit; N]);
+
+impl<const N: usize> From<DiagnosticLine<N>> for u64 {
+    fn from(line: DiagnosticLine<N>) -> u64 {
+        line.0.into_iter().fold(0u64, |acc, bit| match bit {
+            DiagnosticBit::Zero => 2 * acc,
+            DiagnosticBit::One => 2 * acc + 1,
+        })
+    }
+}
+
+impl<const N: usize> Default for DiagnosticLine<N> {
+    fn default() -> Self {
+        DiagnosticLine::<N>([DiagnosticBit::Zero; N])
+    }
+}
+
+impl<const N: usize> FromStr for DiagnosticLine<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bits = s.trim();
+        if bits.len() != N {
+            return Err(ParseError::new(s, bits, format!("{} bits", N)));
+        }
+
+        let elts = bits
+            .char_indices()
+            .map(|(i, c)| match c {
+                '0' => Ok(DiagnosticBit::Zero),
+                '1' => Ok(DiagnosticBit::One),
+                _ => Err(ParseError::new(s, &bits[i..i + c.len_utf8()], "0 or 1")),
+            })
+            .collect::<Result<Vec<DiagnosticBit>, _>>()?;
+        Ok(DiagnosticLine::<N>(elts.try_into().unwrap()))
+    }
+}
+
+fn bit_partition<const N: usize>(
+    lines: Vec<DiagnosticLine<N>>,
+    bit_pos: usize,
+) -> (Vec<DiagnosticLine<N>>, Vec<DiagnosticLine<N>>) {
+    // the first element for the ones  the second for the zeros
+
+    lines
+        .into_iter()
+        .partition(|line| line.0[bit_pos] == DiagnosticBit::One)
+}
+
+fn get_oxygen_rating<const N: usize>(lines: Vec<DiagnosticLine<N>>) -> DiagnosticLine<N> {
+    let mut lines = lines;
+    for i in 0..N {
+        let (ones, zeroes) = bit_partition(lines, i);
+
+        lines = if ones.len() >= zeroes.len() {
+            // if 0 and 1 are equally common, oxygen gets the values with 1
+            ones
+        } else {
+            zeroes
+        };
+
+        if lines.len() == 1 {
+            break;
+        }
+    }
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|&line| line == lines[0]));
+    lines[0]
+}
+
+fn get_co2_rating<const N: usize>(lines: Vec<DiagnosticLine<N>>) -> DiagnosticLine<N> {
+    let mut lines = lines;
+    for i in 0..N {
+        let (ones, zeroes) = bit_partition(lines, i);
+
+        lines = if ones.len() >= zeroes.len() {
+            // if 0 and 1 are equally common, co2 gets the values with 0
+            zeroes
+        } else {
+            ones
+        };
+        if lines.len() == 1 {
+            break;
+        }
+    }
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|&line| line == lines[0]));
+    lines[0]
+}
+
+fn life_support_rating<const N: usize>(input: &str) -> Result<u64, ParseError> {
+    let lines = parse_lines::<DiagnosticLine<N>>(input)?;
+
+    let ox_line = get_oxygen_rating(lines.clone());
+    let ox_rating: u64 = ox_line.into();
+
+    let co2_line = get_co2_rating(lines);
+    let co2_rating: u64 = co2_line.into();
+
+    Ok(ox_rating * co2_rating)
+}
+
+// The example uses 5 bits per line, the puzzle input 12
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let first_line = input.lines().next().unwrap_or("");
+    match first_line.len() {
+        5 => life_support_rating::<5>(input),
+        12 => life_support_rating::<12>(input),
+        _ => Err(ParseError::new(input, first_line, "5 or 12 bits")),
+    }
+    .map(Answer::from)
+}
@@ -0,0 +1,130 @@
+use ansi_term::Colour::Red;
+use aoc_common::{parse_token, Answer, ParseError};
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum BingoGridNumber {
+    Marked(u32),
+    Unmarked(u32),
+}
+
+impl BingoGridNumber {
+    fn mark(&mut self, number: u32) -> bool {
+        match *self {
+            BingoGridNumber::Unmarked(v) if v == number => {
+                *self = BingoGridNumber::Marked(v);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_marked(&self) -> bool {
+        matches!(*self, BingoGridNumber::Marked(_))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct BingoGrid<const N: usize>([[BingoGridNumber; N]; N]);
+
+impl<const N: usize> BingoGrid<N> {
+    fn mark(&mut self, number: u32) -> Option<(usize, usize)> {
+        self.0
+            .iter_mut()
+            .map(|line| line.iter_mut().position(|val| val.mark(number)))
+            .enumerate()
+            .find_map(|(line, pos)| pos.map(|p| (line, p)))
+    }
+
+    fn check_complete(&self, marked_pos: (usize, usize)) -> bool {
+        let completed_col = self.0.iter().all(|line| line[marked_pos.1].is_marked());
+
+        let completed_line = self.0[marked_pos.0].iter().all(|v| v.is_marked());
+
+        completed_col || completed_line
+    }
+
+    fn compute_grid_score(&self) -> u32 {
+        self.0
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|v| match *v {
+                        BingoGridNumber::Unmarked(v) => v,
+                        _ => 0,
+                    })
+                    .sum::<u32>()
+            })
+            .sum()
+    }
+}
+
+impl<const N: usize> fmt::Display for BingoGrid<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|grid_line| {
+            grid_line.iter().try_for_each(|v| match *v {
+                BingoGridNumber::Marked(v) => write!(f, "{:>2} ", Red.paint(v.to_string())),
+                BingoGridNumber::Unmarked(v) => write!(f, "{:>2} ", v),
+            })?;
+            writeln!(f)
+        })
+    }
+}
+
+impl<const N: usize> BingoGrid<N> {
+    fn parse(input: &str, lines: &[&str]) -> Result<Self, ParseError> {
+        let grid_lines = lines
+            .iter()
+            .map(|line| {
+                let numbers = line
+                    .split_whitespace()
+                    .map(|s| parse_token(input, s, "a number").map(BingoGridNumber::Unmarked))
+                    .collect::<Result<Vec<BingoGridNumber>, _>>()?;
+
+                numbers
+                    .try_into()
+                    .map_err(|_| ParseError::new(input, line, format!("{} numbers", N)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let grid = grid_lines
+            .try_into()
+            .map_err(|_| ParseError::at_end(input, format!("{} lines of numbers", N)))?;
+        Ok(BingoGrid::<N>(grid))
+    }
+}
+
+const GRID_SIZE: usize = 5;
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let mut lines_iterator = input.lines();
+    let numbers = lines_iterator
+        .next()
+        .unwrap_or("")
+        .split(',')
+        .map(|s| parse_token(input, s, "a number"))
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let mut grids = Vec::<BingoGrid<GRID_SIZE>>::new();
+
+    while lines_iterator.next().is_some() {
+        let grid_lines: Vec<&str> = lines_iterator.by_ref().take(GRID_SIZE).collect();
+        if grid_lines.is_empty() {
+            break;
+        }
+        grids.push(BingoGrid::parse(input, &grid_lines)?);
+    }
+
+    let score = numbers.into_iter().find_map(|number| {
+        // check if there is a completed grid after marking number
+        let completed_grid_index = grids.iter_mut().position(|grid| {
+            grid.mark(number)
+                .is_some_and(|pos| grid.check_complete(pos))
+        });
+
+        // compute the score if a grid is complete
+        completed_grid_index.map(|grid_index| grids[grid_index].compute_grid_score() * number)
+    });
+
+    Ok(score.unwrap().into())
+}
@@ -0,0 +1,110 @@
+use aoc_common::{parse_lines, parse_token, Answer, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s.trim();
+        let (x, y) = coords
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(s, coords, "a point x,y"))?;
+
+        Ok(Point {
+            x: parse_token(s, x, "a coordinate")?,
+            y: parse_token(s, y, "a coordinate")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VentLine {
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for VentLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once("->")
+            .ok_or_else(|| ParseError::new(s, s, "two points separated by ->"))?;
+        let start = start.parse().map_err(|e: ParseError| e.within(s, start))?;
+        let end = end.parse().map_err(|e: ParseError| e.within(s, end))?;
+
+        Ok(VentLine { start, end })
+    }
+}
+
+#[derive(Debug)]
+struct Diagram(Vec<Vec<u32>>);
+
+impl Diagram {
+    fn new(width: usize, height: usize) -> Self {
+        Diagram(vec![vec![0; width]; height])
+    }
+
+    fn draw_vent_line(&mut self, vent_line: &VentLine) {
+        if vent_line.start.x == vent_line.end.x {
+            // vertical line
+            let x = vent_line.start.x as usize;
+            let min_y = vent_line.start.y.min(vent_line.end.y) as usize;
+            let max_y = vent_line.start.y.max(vent_line.end.y) as usize;
+
+            for i in min_y..=max_y {
+                self.0[i][x] += 1;
+            }
+        } else if vent_line.start.y == vent_line.end.y {
+            // horizontal
+            let y = vent_line.start.y as usize;
+            let min_x = vent_line.start.x.min(vent_line.end.x) as usize;
+            let max_x = vent_line.start.x.max(vent_line.end.x) as usize;
+
+            for i in min_x..=max_x {
+                self.0[y][i] += 1;
+            }
+        } else {
+            // unimplemented!();
+        }
+    }
+
+    fn count_overlaps(&self) -> usize {
+        self.0
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|&v| if v > 1 { 1usize } else { 0usize })
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let vent_lines: Vec<VentLine> = parse_lines(input)?;
+
+    let max_x = vent_lines
+        .iter()
+        .map(|vl| vl.start.x.max(vl.end.x))
+        .max()
+        .unwrap();
+
+    let max_y = vent_lines
+        .iter()
+        .map(|vl| vl.start.y.max(vl.end.y))
+        .max()
+        .unwrap();
+
+    let mut diagram = Diagram::new((max_x + 1) as usize, (max_y + 1) as usize);
+
+    vent_lines.iter().for_each(|vl| diagram.draw_vent_line(vl));
+
+    Ok(diagram.count_overlaps().into())
+}
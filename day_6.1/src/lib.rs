@@ -0,0 +1,42 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+#[derive(Debug, Clone, Copy)]
+struct LanternFish(u8);
+
+impl Default for LanternFish {
+    fn default() -> LanternFish {
+        LanternFish(8)
+    }
+}
+
+impl LanternFish {
+    fn next_day(&mut self) -> Option<LanternFish> {
+        if self.0 == 0 {
+            self.0 = 6;
+            Some(LanternFish::default())
+        } else {
+            self.0 -= 1;
+            None
+        }
+    }
+}
+
+pub fn count_fishes(input: &str, n_days: usize) -> Result<usize, ParseError> {
+    let mut fish_vec: Vec<LanternFish> = input
+        .trim()
+        .split(',')
+        .map(|v| parse_token(input, v, "a timer").map(LanternFish))
+        .collect::<Result<_, _>>()?;
+
+    for _ in 0..n_days {
+        let new_fishes: Vec<LanternFish> =
+            fish_vec.iter_mut().filter_map(|f| f.next_day()).collect();
+        fish_vec.extend(new_fishes);
+    }
+
+    Ok(fish_vec.len())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    count_fishes(input, 80).map(Answer::from)
+}
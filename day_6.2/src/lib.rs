@@ -0,0 +1,46 @@
+use aoc_common::{parse_token, Answer, ParseError};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct LanternFish(u8);
+
+impl Default for LanternFish {
+    fn default() -> LanternFish {
+        LanternFish(8)
+    }
+}
+
+pub fn count_fishes(input: &str, n_days: usize) -> Result<u64, ParseError> {
+    // simulating every fish no longer works because the work to do is exponential
+    // instead, count the fishes sharing the same timer
+    let mut fish_vec: Vec<LanternFish> = input
+        .trim()
+        .split(',')
+        .map(|v| parse_token(input, v, "a timer").map(LanternFish))
+        .collect::<Result<_, _>>()?;
+
+    fish_vec.sort();
+
+    let mut fish_per_timer = [0u64; 9];
+
+    fish_vec
+        .into_iter()
+        .dedup_with_count()
+        .for_each(|(count, val)| fish_per_timer[val.0 as usize] = count as u64);
+
+    for _ in 0..n_days {
+        let mut new_state = [0u64; 9];
+
+        new_state[..8].clone_from_slice(&fish_per_timer[1..9]);
+        new_state[8] = fish_per_timer[0];
+        new_state[6] += fish_per_timer[0];
+
+        fish_per_timer = new_state;
+    }
+
+    Ok(fish_per_timer.iter().sum())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    count_fishes(input, 256).map(Answer::from)
+}
@@ -0,0 +1,28 @@
+use aoc_common::{parse_token, Answer, ParseError};
+
+fn compute_fuel_cost(crab_pos: &[u32], target_pos: u32) -> u32 {
+    crab_pos
+        .iter()
+        .map(|&p| (p as i32 - target_pos as i32).unsigned_abs())
+        .sum()
+}
+
+pub fn find_optimal_position(input: &str) -> Result<(u32, u32), ParseError> {
+    let positions: Vec<u32> = input
+        .trim()
+        .split(',')
+        .map(|v| parse_token(input, v, "a position"))
+        .collect::<Result<_, _>>()?;
+
+    let min = *positions.iter().min().unwrap();
+    let max = *positions.iter().max().unwrap();
+
+    Ok((min..=max)
+        .map(|target| (target, compute_fuel_cost(&positions, target)))
+        .min_by(|(_, f1), (_, f2)| f1.cmp(f2))
+        .unwrap())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    find_optimal_position(input).map(|r| r.1.into())
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Answer, ParseError};
+
+fn parse_len(len: usize) -> Option<u8> {
+    match len {
+        2 => Some(1),
+        4 => Some(4),
+        3 => Some(7),
+        7 => Some(8),
+        _ => None,
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, suffix) = line
+                .split_once('|')
+                .ok_or_else(|| ParseError::new(input, line, "patterns | digits"))?;
+            Ok(suffix
+                .split_whitespace()
+                .map(|subs| subs.len())
+                .filter_map(parse_len)
+                .count())
+        })
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
+}
@@ -0,0 +1,24 @@
+use aoc_common::{parse_digit_grid, Answer, Grid, ParseError};
+
+fn get_low_points(height_map: &Grid<u8>) -> Vec<(usize, usize)> {
+    height_map
+        .positions()
+        .filter(|&p| {
+            height_map
+                .neighbours4(p)
+                .all(|n| height_map[p] < height_map[n])
+        })
+        .collect()
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let height_map = parse_digit_grid(input)?;
+
+    let low_points = get_low_points(&height_map);
+
+    Ok(low_points
+        .iter()
+        .map(|&p| (height_map[p] + 1) as u64)
+        .sum::<u64>()
+        .into())
+}
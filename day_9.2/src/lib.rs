@@ -0,0 +1,56 @@
+use aoc_common::{parse_digit_grid, Answer, Grid, ParseError};
+use std::collections::VecDeque;
+
+fn get_low_points(height_map: &Grid<u8>) -> Vec<(usize, usize)> {
+    height_map
+        .positions()
+        .filter(|&p| {
+            height_map
+                .neighbours4(p)
+                .all(|n| height_map[p] < height_map[n])
+        })
+        .collect()
+}
+
+// A basin flows down to its low point: from any of its cells, we can go up to every
+// higher neighbour, except for the 9s which do not belong to any basin.
+fn basin_size(height_map: &Grid<u8>, visited: &mut Grid<bool>, pos: (usize, usize)) -> usize {
+    let mut size = 0usize;
+    let mut vertices = VecDeque::<(usize, usize)>::new();
+    vertices.push_back(pos);
+
+    while let Some(p) = vertices.pop_back() {
+        if !visited[p] {
+            size += 1;
+            visited[p] = true;
+
+            vertices.extend(
+                height_map
+                    .neighbours4(p)
+                    .filter(|&n| height_map[p] < height_map[n] && height_map[n] != 9),
+            );
+        }
+    }
+
+    size
+}
+
+fn compute_basin_sizes(height_map: &Grid<u8>, low_points: &[(usize, usize)]) -> Vec<usize> {
+    let mut visited = Grid::new(height_map.width(), height_map.height(), false);
+
+    low_points
+        .iter()
+        .map(|point| basin_size(height_map, &mut visited, *point))
+        .collect()
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let height_map = parse_digit_grid(input)?;
+
+    let low_points = get_low_points(&height_map);
+
+    let mut basin_sizes = compute_basin_sizes(&height_map, &low_points);
+    basin_sizes.sort_unstable();
+    let l = basin_sizes.len();
+    Ok((basin_sizes[l - 1] * basin_sizes[l - 2] * basin_sizes[l - 3]).into())
+}